@@ -166,7 +166,7 @@ fn main() {
   vb.clear();
   let mut vu = StaticVec::<usize, 8>::new();
   vu.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
-  println!("{}", vu.drain(2..5).iter().find(|&&i| i == 4).unwrap());
+  println!("{}", vu.drain(2..5).find(|&i| i == 4).unwrap());
   let vvu: StaticVec<&usize, 4> = vu.iter().collect();
   for i in &vvu {
     println!("{}", i);
@@ -280,7 +280,7 @@ fn main() {
     MyOtherStruct { s: "e" },
     MyOtherStruct { s: "f" },
   ];
-  let mut newstructs = structs.drain_filter(|s| s.s < "d");
+  let mut newstructs: StaticVec<MyOtherStruct, 6> = structs.drain_filter(|s| s.s < "d").collect();
   for s in &structs {
     println!("{}", s.s);
   }
@@ -36,6 +36,62 @@ pub struct StaticVecIntoIter<T, const N: usize> {
   pub(crate) data: StaticVec<T, N>,
 }
 
+/// A "draining" iterator, analogous to [`vec::Drain`](alloc::vec::Drain).
+/// Instances of [`StaticVecDrain`](crate::iterators::StaticVecDrain) are created
+/// by the [`drain`](crate::StaticVec::drain) method on [`StaticVec`](crate::StaticVec).
+pub struct StaticVecDrain<'a, T: 'a, const N: usize> {
+  // The index of the first element of the tail (that is, the elements that come after the drained
+  // range) in the source StaticVec.
+  pub(crate) start: usize,
+  // The number of elements in the tail.
+  pub(crate) length: usize,
+  pub(crate) iter: StaticVecIterConst<'a, T, N>,
+  pub(crate) vec: *mut StaticVec<T, N>,
+}
+
+/// A lazily-evaluated "draining" iterator that removes and yields only the elements of a
+/// StaticVec for which a predicate returns true. Instances of
+/// [`StaticVecDrainFilter`](crate::iterators::StaticVecDrainFilter) are created by the
+/// [`drain_filter`](crate::StaticVec::drain_filter) method on [`StaticVec`](crate::StaticVec).
+pub struct StaticVecDrainFilter<'a, T: 'a, F, const N: usize>
+where F: FnMut(&mut T) -> bool {
+  pub(crate) vec: &'a mut StaticVec<T, N>,
+  // The index of the next element to pass to `filter`.
+  pub(crate) index: usize,
+  // The number of elements removed so far, which is also how far each kept element needs to be
+  // shifted to the left.
+  pub(crate) deleted: usize,
+  pub(crate) old_length: usize,
+  pub(crate) filter: F,
+  // Set while `filter` is running, so that `drop` knows not to call it again if it panicked.
+  pub(crate) panic_flag: bool,
+}
+
+/// An iterator over fixed-size array references to the elements of a StaticVec, similar to
+/// [`ChunksExact`](core::slice::ChunksExact). Instances of
+/// [`StaticVecArrayChunks`](crate::iterators::StaticVecArrayChunks) are created by the
+/// [`array_chunks`](crate::StaticVec::array_chunks) method on [`StaticVec`](crate::StaticVec).
+pub struct StaticVecArrayChunks<'a, T: 'a, const C: usize> {
+  pub(crate) iter: slice::Iter<'a, [T; C]>,
+  // The leftover elements that don't make up a complete chunk.
+  pub(crate) remainder: &'a [T],
+}
+
+/// A "lending" iterator over overlapping mutable windows of the elements of a StaticVec, similar
+/// to [`Windows`](core::slice::Windows). Instances of
+/// [`StaticVecWindowsMut`](crate::iterators::StaticVecWindowsMut) are created by the
+/// [`windows_mut`](crate::StaticVec::windows_mut) method on [`StaticVec`](crate::StaticVec).
+///
+/// Since consecutive windows overlap, this type can't implement [`Iterator`](core::iter::Iterator):
+/// each window returned by its [`next`](crate::iterators::StaticVecWindowsMut::next) method
+/// borrows the iterator itself mutably, and so must be let go of before the next one is requested.
+pub struct StaticVecWindowsMut<'a, T: 'a> {
+  pub(crate) slice: &'a mut [T],
+  pub(crate) size: usize,
+  // The index of the first element of the next window.
+  pub(crate) position: usize,
+}
+
 impl<'a, T: 'a, const N: usize> StaticVecIterConst<'a, T, N> {
   #[cfg(feature = "std")]
   #[doc(cfg(feature = "std"))]
@@ -82,6 +138,26 @@ impl<'a, T: 'a, const N: usize> Iterator for StaticVecIterConst<'a, T, N> {
     }
   }
 
+  #[inline(always)]
+  fn nth(&mut self, n: usize) -> Option<Self::Item> {
+    if n >= distance_between(self.end, self.start) {
+      // Skipping past the end leaves the iterator empty, as with `slice::Iter`.
+      self.start = self.end;
+      None
+    } else {
+      self.start = match intrinsics::size_of::<T>() {
+        0 => (self.start as usize + n) as *const _,
+        _ => unsafe { self.start.add(n) },
+      };
+      self.next()
+    }
+  }
+
+  #[inline(always)]
+  fn last(mut self) -> Option<Self::Item> {
+    self.next_back()
+  }
+
   #[inline(always)]
   fn size_hint(&self) -> (usize, Option<usize>) {
     let len = distance_between(self.end, self.start);
@@ -187,6 +263,26 @@ impl<'a, T: 'a, const N: usize> Iterator for StaticVecIterMut<'a, T, N> {
     }
   }
 
+  #[inline(always)]
+  fn nth(&mut self, n: usize) -> Option<Self::Item> {
+    if n >= distance_between(self.end, self.start) {
+      // Skipping past the end leaves the iterator empty, as with `slice::Iter`.
+      self.start = self.end;
+      None
+    } else {
+      self.start = match intrinsics::size_of::<T>() {
+        0 => (self.start as usize + n) as *mut _,
+        _ => unsafe { self.start.add(n) },
+      };
+      self.next()
+    }
+  }
+
+  #[inline(always)]
+  fn last(mut self) -> Option<Self::Item> {
+    self.next_back()
+  }
+
   #[inline(always)]
   fn size_hint(&self) -> (usize, Option<usize>) {
     let len = distance_between(self.end, self.start);
@@ -336,3 +432,281 @@ impl<T, const N: usize> Drop for StaticVecIntoIter<T, N> {
     }
   }
 }
+
+impl<'a, T: 'a, const N: usize> StaticVecDrain<'a, T, N> {
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline(always)]
+  /// Returns a string displaying the current values of the
+  /// iterator's `start` and `end` elements on two separate lines.
+  /// Locally requires that `T` implements [Debug](core::fmt::Debug)
+  /// to make it possible to pretty-print the elements.
+  pub fn bounds_to_string(&self) -> String
+  where T: Debug {
+    self.iter.bounds_to_string()
+  }
+
+  #[inline(always)]
+  /// Returns an immutable slice consisting of the elements that have not yet been yielded
+  /// by the iterator.
+  pub fn as_slice(&self) -> &[T] {
+    self.iter.as_slice()
+  }
+}
+
+impl<'a, T: 'a, const N: usize> Iterator for StaticVecDrain<'a, T, N> {
+  type Item = T;
+  #[inline(always)]
+  fn next(&mut self) -> Option<T> {
+    // Safety: the elements in the drained range are no longer considered part of the source
+    // StaticVec, so each of them is read out exactly once here.
+    self.iter.next().map(|val| unsafe { ptr::read(val) })
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
+
+impl<'a, T: 'a, const N: usize> DoubleEndedIterator for StaticVecDrain<'a, T, N> {
+  #[inline(always)]
+  fn next_back(&mut self) -> Option<T> {
+    self.iter.next_back().map(|val| unsafe { ptr::read(val) })
+  }
+}
+
+impl<'a, T: 'a, const N: usize> ExactSizeIterator for StaticVecDrain<'a, T, N> {
+  #[inline(always)]
+  fn len(&self) -> usize {
+    self.iter.len()
+  }
+
+  #[inline(always)]
+  fn is_empty(&self) -> bool {
+    self.iter.is_empty()
+  }
+}
+
+impl<'a, T: 'a, const N: usize> FusedIterator for StaticVecDrain<'a, T, N> {}
+unsafe impl<'a, T: 'a, const N: usize> TrustedLen for StaticVecDrain<'a, T, N> {}
+unsafe impl<'a, T: 'a + Sync, const N: usize> Sync for StaticVecDrain<'a, T, N> {}
+unsafe impl<'a, T: 'a + Send, const N: usize> Send for StaticVecDrain<'a, T, N> {}
+
+impl<'a, T: 'a + Debug, const N: usize> Debug for StaticVecDrain<'a, T, N> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_list().entries(self.as_slice()).finish()
+  }
+}
+
+impl<'a, T: 'a, const N: usize> Drop for StaticVecDrain<'a, T, N> {
+  #[inline]
+  fn drop(&mut self) {
+    // Drop any elements in the drained range that were not yielded.
+    self.for_each(drop);
+    // Move the tail back down to close the gap left by the drained range. The source StaticVec's
+    // length was already set to the start of the drained range by `drain`, so if this iterator is
+    // leaked instead of dropped, the tail is simply leaked along with it rather than
+    // double-dropped.
+    if self.length > 0 {
+      unsafe {
+        let vec = &mut *self.vec;
+        let old_length = vec.length;
+        vec
+          .ptr_at_unchecked(self.start)
+          .copy_to(vec.mut_ptr_at_unchecked(old_length), self.length);
+        vec.set_len(old_length + self.length);
+      }
+    }
+  }
+}
+
+impl<'a, T: 'a, F, const N: usize> Iterator for StaticVecDrainFilter<'a, T, F, N>
+where F: FnMut(&mut T) -> bool
+{
+  type Item = T;
+  #[inline]
+  fn next(&mut self) -> Option<T> {
+    unsafe {
+      while self.index < self.old_length {
+        let p = self.vec.as_mut_ptr();
+        let current = p.add(self.index);
+        self.panic_flag = true;
+        let drained = (self.filter)(&mut *current);
+        self.panic_flag = false;
+        self.index += 1;
+        if drained {
+          self.deleted += 1;
+          return Some(current.read());
+        } else if self.deleted > 0 {
+          current.copy_to_nonoverlapping(current.sub(self.deleted), 1);
+        }
+      }
+    }
+    None
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (0, Some(self.old_length - self.index))
+  }
+}
+
+impl<'a, T: 'a, F, const N: usize> FusedIterator for StaticVecDrainFilter<'a, T, F, N> where F: FnMut(&mut T) -> bool {}
+
+impl<'a, T: 'a + Debug, F, const N: usize> Debug for StaticVecDrainFilter<'a, T, F, N>
+where F: FnMut(&mut T) -> bool
+{
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    // Only the elements that haven't been passed to `filter` yet are shown.
+    let remaining = unsafe {
+      slice::from_raw_parts(
+        self.vec.as_ptr().add(self.index),
+        self.old_length - self.index,
+      )
+    };
+    f.debug_struct("StaticVecDrainFilter")
+      .field("remaining", &remaining)
+      .finish()
+  }
+}
+
+impl<'a, T: 'a, F, const N: usize> Drop for StaticVecDrainFilter<'a, T, F, N>
+where F: FnMut(&mut T) -> bool
+{
+  #[inline]
+  fn drop(&mut self) {
+    // Remove and drop any matching elements that were not yielded, unless `filter` itself has
+    // panicked, in which case we avoid calling it again.
+    if !self.panic_flag {
+      self.for_each(drop);
+    }
+    // Move any elements that were never passed to `filter` down to close the gap. The source
+    // StaticVec's length was set to 0 by `drain_filter`, so if this iterator is leaked instead of
+    // dropped, everything is simply leaked along with it rather than double-dropped.
+    unsafe {
+      let p = self.vec.as_mut_ptr();
+      if self.deleted > 0 && self.index < self.old_length {
+        p.add(self.index).copy_to(
+          p.add(self.index - self.deleted),
+          self.old_length - self.index,
+        );
+      }
+      self.vec.set_len(self.old_length - self.deleted);
+    }
+  }
+}
+
+impl<'a, T: 'a> StaticVecWindowsMut<'a, T> {
+  /// Returns the next window in `Some`, or `None` if there are no windows left. Any mutations
+  /// made through a window are visible in all of the later windows that overlap with it.
+  #[allow(clippy::should_implement_trait)]
+  #[inline]
+  pub fn next(&mut self) -> Option<&mut [T]> {
+    let end = self.position + self.size;
+    if end > self.slice.len() {
+      return None;
+    }
+    let res = unsafe { self.slice.get_unchecked_mut(self.position..end) };
+    self.position += 1;
+    Some(res)
+  }
+
+  /// Returns the number of windows remaining.
+  #[inline(always)]
+  pub fn len(&self) -> usize {
+    (self.slice.len() + 1).saturating_sub(self.position + self.size)
+  }
+
+  /// Returns true if there are no windows remaining.
+  #[inline(always)]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+impl<'a, T: 'a + Debug> Debug for StaticVecWindowsMut<'a, T> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_struct("StaticVecWindowsMut")
+      .field(
+        "remaining",
+        &&self.slice[self.position.min(self.slice.len())..],
+      )
+      .field("size", &self.size)
+      .finish()
+  }
+}
+
+impl<'a, T: 'a, const C: usize> StaticVecArrayChunks<'a, T, C> {
+  #[inline(always)]
+  /// Returns an immutable slice consisting of the chunks that have not yet been yielded
+  /// by the iterator.
+  pub fn as_slice(&self) -> &'a [[T; C]] {
+    self.iter.as_slice()
+  }
+
+  #[inline(always)]
+  /// Returns the leftover elements at the end of the source StaticVec that do not make up a
+  /// complete chunk, and so will never be yielded by the iterator. This will be an empty slice
+  /// if the length of the StaticVec was evenly divisible by `C`.
+  pub fn remainder(&self) -> &'a [T] {
+    self.remainder
+  }
+}
+
+impl<'a, T: 'a, const C: usize> Iterator for StaticVecArrayChunks<'a, T, C> {
+  type Item = &'a [T; C];
+  #[inline(always)]
+  fn next(&mut self) -> Option<Self::Item> {
+    self.iter.next()
+  }
+
+  #[inline(always)]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.iter.size_hint()
+  }
+}
+
+impl<'a, T: 'a, const C: usize> DoubleEndedIterator for StaticVecArrayChunks<'a, T, C> {
+  #[inline(always)]
+  fn next_back(&mut self) -> Option<Self::Item> {
+    self.iter.next_back()
+  }
+}
+
+impl<'a, T: 'a, const C: usize> ExactSizeIterator for StaticVecArrayChunks<'a, T, C> {
+  #[inline(always)]
+  fn len(&self) -> usize {
+    self.iter.len()
+  }
+
+  #[inline(always)]
+  fn is_empty(&self) -> bool {
+    self.iter.is_empty()
+  }
+}
+
+impl<'a, T: 'a, const C: usize> FusedIterator for StaticVecArrayChunks<'a, T, C> {}
+unsafe impl<'a, T: 'a, const C: usize> TrustedLen for StaticVecArrayChunks<'a, T, C> {}
+
+impl<'a, T: 'a, const C: usize> Clone for StaticVecArrayChunks<'a, T, C> {
+  #[inline(always)]
+  fn clone(&self) -> Self {
+    Self {
+      iter: self.iter.clone(),
+      remainder: self.remainder,
+    }
+  }
+}
+
+impl<'a, T: 'a + Debug, const C: usize> Debug for StaticVecArrayChunks<'a, T, C> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_list()
+      .entries(self.as_slice().iter().map(|chunk| chunk as &[T]))
+      .finish()
+  }
+}
@@ -16,15 +16,20 @@
 #![feature(trusted_len)]
 
 pub use crate::iterators::*;
+pub use crate::pod::Pod;
+pub use crate::reader::StaticVecReader;
+pub use crate::ring::StaticRingBuffer;
 pub use crate::trait_impls::*;
 use crate::utils::*;
-use core::cmp::{Ord, PartialEq};
+use core::cmp::{Ord, Ordering, PartialEq};
 use core::intrinsics;
+use core::iter;
 use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
-use core::ops::{Bound::Excluded, Bound::Included, Bound::Unbounded, RangeBounds};
+use core::ops::RangeBounds;
 use core::ptr;
-use core::slice;
+use core::slice::{self, SliceIndex};
+use core::sync::atomic;
 
 #[cfg(any(feature = "std", rustdoc))]
 extern crate alloc;
@@ -38,6 +43,9 @@ use alloc::vec::Vec;
 mod iterators;
 #[macro_use]
 mod macros;
+mod pod;
+mod reader;
+mod ring;
 mod trait_impls;
 #[doc(hidden)]
 pub mod utils;
@@ -59,6 +67,41 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Returns a new StaticVec instance with a length of `len`, but whose contents are entirely
+  /// uninitialized. This is intended for use with APIs (such as C functions) that fill a buffer
+  /// through a raw pointer, which can be obtained from
+  /// [`as_mut_ptr`](crate::StaticVec::as_mut_ptr).
+  ///
+  /// # Safety
+  ///
+  /// It is up to the caller to ensure that `len` is less than or equal to the StaticVec's
+  /// constant `N` parameter, and that all `len` elements are initialized before any of them are
+  /// read, and before the StaticVec is dropped. Failure to do so will almost certainly result in
+  /// undefined behavior.
+  ///
+  /// Example usage:
+  /// ```
+  /// // Stands in for a C function that fills a caller-provided buffer.
+  /// unsafe extern "C" fn fill_buffer(buf: *mut u8, len: usize) {
+  ///   for i in 0..len {
+  ///     *buf.add(i) = i as u8 * 2;
+  ///   }
+  /// }
+  ///
+  /// let v = unsafe {
+  ///   let mut v = StaticVec::<u8, 16>::with_len(4);
+  ///   fill_buffer(v.as_mut_ptr(), v.len());
+  ///   v
+  /// };
+  /// assert_eq!(v, [0, 2, 4, 6]);
+  /// ```
+  #[inline(always)]
+  pub unsafe fn with_len(len: usize) -> Self {
+    let mut res = Self::new();
+    res.set_len(len);
+    res
+  }
+
   /// Returns a new StaticVec instance filled with the contents, if any, of a slice reference,
   /// which can be either `&mut` or `&` as if it is `&mut` it will implicitly coerce to `&`.
   /// If the slice has a length greater than the StaticVec's declared capacity,
@@ -82,6 +125,52 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Returns a new StaticVec instance filled with `times` consecutive copies of `pattern`.
+  ///
+  /// Panics if the combined length of all of the copies is greater than `N`.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn from_repeating(pattern: &[T], times: usize) -> Self
+  where T: Copy {
+    let pattern_length = pattern.len();
+    let total_length = pattern_length.saturating_mul(times);
+    assert!(
+      total_length <= N,
+      "Combined length {} must be less than or equal to {}!",
+      total_length,
+      N
+    );
+    let mut res = Self::new();
+    unsafe {
+      let mut p = res.as_mut_ptr();
+      for _ in 0..times {
+        pattern.as_ptr().copy_to_nonoverlapping(p, pattern_length);
+        p = p.add(pattern_length);
+      }
+      res.set_len(total_length);
+    }
+    res
+  }
+
+  /// Returns a new StaticVec instance filled with clones of the contents, if any, of a slice
+  /// reference. If the slice has a length greater than the StaticVec's declared capacity, any
+  /// contents after that point are ignored. This is the [`Clone`](core::clone::Clone)-based
+  /// counterpart to [`new_from_slice`](crate::StaticVec::new_from_slice), and so does not require
+  /// that `T` implements [`Copy`](core::marker::Copy). If one of the calls to `clone` panics,
+  /// all of the elements cloned before it are dropped properly.
+  #[inline]
+  pub fn clone_from_slice_new(values: &[T]) -> Self
+  where T: Clone {
+    let mut res = Self::new();
+    for value in values.iter().take(N) {
+      // Safety: we take at most `N` values, so `res` can't be full here. Since `res.length`
+      // is incremented after each write, `res` drops exactly the initialized elements if a
+      // later `clone` panics.
+      unsafe { res.push_unchecked(value.clone()) };
+    }
+    res
+  }
+
   /// Returns a new StaticVec instance filled with the contents, if any, of an array.
   /// If the array has a length greater than the StaticVec's declared capacity,
   /// any contents after that point are ignored.
@@ -130,6 +219,24 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Returns a new StaticVec instance filled with the items yielded by `iter`, or an error if
+  /// `iter` yields more items than the StaticVec's declared capacity. Unlike the
+  /// [`FromIterator`](core::iter::FromIterator) implementation, which ignores any such surplus
+  /// items, this treats them as a genuine error. In that case every item already pulled from
+  /// `iter` is dropped, and `iter` is not advanced any further.
+  #[inline]
+  pub fn try_from_iter<I>(iter: I) -> Result<Self, &'static str>
+  where I: IntoIterator<Item = T> {
+    let mut res = Self::new();
+    for item in iter {
+      if res.is_full() {
+        return Err("Insufficient remaining capacity!");
+      }
+      unsafe { res.push_unchecked(item) };
+    }
+    Ok(res)
+  }
+
   /// Returns a new StaticVec instance filled with the return value of an initializer function.
   /// The length field of the newly created StaticVec will be equal to its capacity.
   ///
@@ -191,6 +298,33 @@ impl<T, const N: usize> StaticVec<T, N> {
     res
   }
 
+  /// Returns a new StaticVec instance filled with the return value of a fallible initializer
+  /// function, or the first error it returns if any of the calls fail. Since the length of the
+  /// StaticVec is only incremented after each successful call, all of the values produced before
+  /// the failure are dropped exactly once when returning early (or if `initializer` panics).
+  ///
+  /// Example usage:
+  /// ```
+  /// let mut i = 0;
+  /// let v = StaticVec::<i32, 4>::try_filled_with(|| {
+  ///   i += 1;
+  ///   if i < 3 { Ok(i) } else { Err("Failed on the third call!") }
+  /// });
+  /// assert_eq!(v, Err("Failed on the third call!"));
+  /// ```
+  #[inline]
+  pub fn try_filled_with<F, E>(mut initializer: F) -> Result<Self, E>
+  where F: FnMut() -> Result<T, E> {
+    let mut res = Self::new();
+    for i in 0..N {
+      unsafe {
+        res.data.get_unchecked_mut(i).write(initializer()?);
+        res.length += 1;
+      }
+    }
+    Ok(res)
+  }
+
   /// Returns the current length of the StaticVec.
   /// Just as for a normal [`Vec`](alloc::vec::Vec), this means the number of elements that
   /// have been added to it with [`push`](crate::StaticVec::push),
@@ -297,6 +431,215 @@ impl<T, const N: usize> StaticVec<T, N> {
     unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.length) }
   }
 
+  /// Returns a mutable reference to a slice of the StaticVec's uninhabited area, that is, the
+  /// positions from `length` to `N`, as [`MaybeUninit<T>`](core::mem::MaybeUninit). Writing to
+  /// these does not change the length of the StaticVec; once some of them have been initialized,
+  /// [`set_len`](crate::StaticVec::set_len) can be used to mark them as inhabited. For a fully
+  /// safe alternative, see [`init_spare`](crate::StaticVec::init_spare).
+  #[inline(always)]
+  pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+    unsafe { self.data.get_unchecked_mut(self.length..) }
+  }
+
+  /// Asserts that `count` is less than or equal to the StaticVec's remaining capacity, and if so
+  /// initializes the next `count` positions of its uninhabited area with the return values of
+  /// `f`, increasing its length accordingly. `f` is passed the index in the StaticVec of each
+  /// element it is initializing. If `f` panics, every element initialized before that point is
+  /// kept.
+  #[inline]
+  pub fn init_spare<F>(&mut self, count: usize, mut f: F)
+  where F: FnMut(usize) -> T {
+    assert!(
+      count <= self.remaining_capacity(),
+      "Provided count {} must be less than or equal to {}!",
+      count,
+      self.remaining_capacity()
+    );
+    let new_length = self.length + count;
+    while self.length < new_length {
+      let value = f(self.length);
+      unsafe { self.data.get_unchecked_mut(self.length).write(value) };
+      self.length += 1;
+    }
+  }
+
+  /// Returns a constant reference to the StaticVec's internal array as a `[T; N]` if the
+  /// StaticVec is full (meaning every position in the array is initialized), or `None` otherwise.
+  #[inline(always)]
+  pub fn as_array(&self) -> Option<&[T; N]> {
+    if self.is_full() {
+      // Safety: all `N` elements are initialized, and `[MaybeUninit<T>; N]` has the same
+      // layout as `[T; N]`.
+      Some(unsafe { &*(self.data.as_ptr() as *const [T; N]) })
+    } else {
+      None
+    }
+  }
+
+  /// Returns a copy of the StaticVec's internal array as a `[T; N]` in `Some` if the StaticVec
+  /// is full, or `None` otherwise. This is the non-consuming counterpart to
+  /// [`into_array`](crate::StaticVec::into_array).
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline(always)]
+  pub fn copied_array(&self) -> Option<[T; N]>
+  where T: Copy {
+    self.as_array().copied()
+  }
+
+  /// Returns an array of `N` disjoint mutable references, one to each element of the StaticVec in
+  /// order, if the StaticVec is full, or `None` otherwise.
+  #[inline]
+  pub fn each_mut(&mut self) -> Option<[&mut T; N]> {
+    if self.is_not_full() {
+      return None;
+    }
+    let p = self.as_mut_ptr();
+    let mut res: [MaybeUninit<&mut T>; N] = MaybeUninit::uninit_array();
+    unsafe {
+      // Safety: every element is initialized, and each reference points to a different one.
+      for i in 0..N {
+        res.get_unchecked_mut(i).write(&mut *p.add(i));
+      }
+      Some(res.as_ptr().cast::<[&mut T; N]>().read())
+    }
+  }
+
+  /// Consumes the StaticVec, returning its internal array by value as a `[T; N]` if it is full.
+  /// If it is not full, the StaticVec is returned unchanged in `Err`. This is the inverse of
+  /// [`new_from_array`](crate::StaticVec::new_from_array) when `N2` is equal to `N`.
+  #[inline]
+  pub fn into_array(self) -> Result<[T; N], Self> {
+    if self.is_full() {
+      // Safety: see `as_array`. `self` is forgotten immediately afterwards, so the elements
+      // are not dropped twice.
+      let res = unsafe { self.data.as_ptr().cast::<[T; N]>().read() };
+      mem::forget(self);
+      Ok(res)
+    } else {
+      Err(self)
+    }
+  }
+
+  /// Returns a view of the StaticVec's inhabited area as a slice of raw bytes, in native byte
+  /// order.
+  /// Locally requires that `T` implements [`Pod`](crate::Pod) to avoid soundness issues.
+  #[inline(always)]
+  pub fn as_bytes(&self) -> &[u8]
+  where T: Pod {
+    // Safety: `T: Pod` guarantees there are no padding bytes, so every byte is initialized.
+    unsafe {
+      slice::from_raw_parts(
+        self.as_ptr() as *const u8,
+        self.length * mem::size_of::<T>(),
+      )
+    }
+  }
+
+  /// Returns a view of the StaticVec's inhabited area reinterpreted as a slice of `U`, or an
+  /// error if the total size in bytes of the inhabited area is not a multiple of the size of `U`,
+  /// or if the StaticVec's data is not sufficiently aligned for `U`. Note that the latter can
+  /// only happen when `U` has a greater alignment than `T`.
+  /// Locally requires that both `T` and `U` implement [`Pod`](crate::Pod) to avoid soundness
+  /// issues.
+  #[inline]
+  pub fn reinterpret_as<U: Pod>(&self) -> Result<&[U], &'static str>
+  where T: Pod {
+    let bytes = self.as_bytes();
+    let size = mem::size_of::<U>();
+    let length = match size {
+      0 if bytes.is_empty() => 0,
+      0 => return Err("Length in bytes must be a multiple of the size of `U`!"),
+      _ if bytes.len() % size != 0 => {
+        return Err("Length in bytes must be a multiple of the size of `U`!")
+      }
+      _ => bytes.len() / size,
+    };
+    if (bytes.as_ptr() as usize) % mem::align_of::<U>() != 0 {
+      return Err("Data must be aligned for `U`!");
+    }
+    // Safety: `U: Pod` means that any bytes make up a valid `U`, and we've checked both the size
+    // and the alignment.
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const U, length) })
+  }
+
+  /// Returns a new StaticVec instance whose elements are read from the raw bytes of `bytes` in
+  /// native byte order, as produced by [`as_bytes`](crate::StaticVec::as_bytes), or an error
+  /// if the length of `bytes` is not a multiple of the size of `T` or the elements wouldn't
+  /// fit in the StaticVec. As the bytes are copied rather than borrowed, `bytes` does not need
+  /// to be aligned for `T`.
+  /// Locally requires that `T` implements [`Pod`](crate::Pod) to avoid soundness issues.
+  #[inline]
+  pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, &'static str>
+  where T: Pod {
+    let size = mem::size_of::<T>();
+    let length = match size {
+      0 if bytes.is_empty() => 0,
+      0 => return Err("Length of `bytes` must be a multiple of the size of `T`!"),
+      _ if bytes.len() % size != 0 => {
+        return Err("Length of `bytes` must be a multiple of the size of `T`!")
+      }
+      _ => bytes.len() / size,
+    };
+    if length > N {
+      return Err("Insufficient remaining capacity!");
+    }
+    let mut res = Self::new();
+    // Safety: `T: Pod` means that any bytes make up a valid `T`, and we copy byte-by-byte so
+    // the alignment of `bytes` doesn't matter.
+    unsafe {
+      bytes
+        .as_ptr()
+        .copy_to_nonoverlapping(res.as_mut_ptr() as *mut u8, bytes.len());
+      res.set_len(length);
+    }
+    Ok(res)
+  }
+
+  /// Returns a constant reference to the element (or subslice, if `index` is a range) of the
+  /// StaticVec at `index` in `Some` if it is within the bounds of the StaticVec's inhabited area,
+  /// or `None` otherwise. This has the same semantics as
+  /// [`slice::get`](https://doc.rust-lang.org/nightly/std/primitive.slice.html#method.get).
+  #[inline(always)]
+  pub fn get<I>(&self, index: I) -> Option<&I::Output>
+  where I: SliceIndex<[T]> {
+    self.as_slice().get(index)
+  }
+
+  /// Returns a mutable reference to the element (or subslice, if `index` is a range) of the
+  /// StaticVec at `index` in `Some` if it is within the bounds of the StaticVec's inhabited area,
+  /// or `None` otherwise.
+  #[inline(always)]
+  pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+  where I: SliceIndex<[T]> {
+    self.as_mut_slice().get_mut(index)
+  }
+
+  /// Returns an array of `M` mutable references to the elements of the StaticVec at each of the
+  /// given `indices`, in the same order, in `Some`. Returns `None` if any of the indices are
+  /// outside of the StaticVec's inhabited area, or if any two of them are equal (since that
+  /// would mean handing out two mutable references to the same element.)
+  #[inline]
+  pub fn get_many_mut<const M: usize>(&mut self, indices: [usize; M]) -> Option<[&mut T; M]> {
+    let index_slice: &[usize] = &indices;
+    for (i, &index) in index_slice.iter().enumerate() {
+      if index >= self.length || index_slice[..i].contains(&index) {
+        return None;
+      }
+    }
+    let p = self.as_mut_ptr();
+    let mut res: [MaybeUninit<&mut T>; M] = MaybeUninit::uninit_array();
+    unsafe {
+      // Safety: every index is in bounds and distinct, so each reference points to a different
+      // initialized element.
+      for i in 0..M {
+        res
+          .get_unchecked_mut(i)
+          .write(&mut *p.add(*index_slice.get_unchecked(i)));
+      }
+      Some(res.as_ptr().cast::<[&mut T; M]>().read())
+    }
+  }
+
   /// Returns a constant reference to the element of the StaticVec at `index`,
   /// if `index` is within the range `0..length`. No checks are performed to
   /// ensure that is the case, so this function is marked `unsafe` and should
@@ -465,6 +808,58 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Pushes `value` to the front of the StaticVec if its current length is less than its
+  /// capacity, or returns an error indicating there's no remaining capacity otherwise. All
+  /// existing values are shifted to the right, so this is O(n) in the current length.
+  #[inline]
+  pub fn try_push_front(&mut self, value: T) -> Result<(), &'static str> {
+    if self.length < N {
+      self.insert(0, value);
+      Ok(())
+    } else {
+      Err("Insufficient remaining capacity!")
+    }
+  }
+
+  /// Pushes a value to the front of the StaticVec, shifting all existing values to the right.
+  /// This is O(n) in the current length. Panics if the collection is full; that is, if
+  /// `self.len() == self.capacity()`.
+  #[inline]
+  pub fn push_front(&mut self, value: T) {
+    assert!(self.length < N, "Insufficient remaining capacity!");
+    self.insert(0, value);
+  }
+
+  /// Removes the value at the first position of the StaticVec and returns it in `Some` if
+  /// the StaticVec has a current length greater than 0, and returns `None` otherwise. All
+  /// remaining values are shifted to the left, so this is O(n) in the current length.
+  #[inline]
+  pub fn pop_front(&mut self) -> Option<T> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(self.remove(0))
+    }
+  }
+
+  /// Removes the last `N2` values of the StaticVec (or all of them, if its current length is
+  /// less than `N2`) and returns them in a new StaticVec with a capacity of `N2`, in their
+  /// original order. This moves all of the values at once rather than one at a time as repeated
+  /// calls to [`pop`](crate::StaticVec::pop) would.
+  #[inline]
+  pub fn pop_n<const N2: usize>(&mut self) -> StaticVec<T, N2> {
+    let count = N2.min(self.length);
+    let mut res = StaticVec::new();
+    self.length -= count;
+    unsafe {
+      self
+        .ptr_at_unchecked(self.length)
+        .copy_to_nonoverlapping(res.as_mut_ptr(), count);
+      res.set_len(count);
+    }
+    res
+  }
+
   /// Returns a constant reference to the first element of the StaticVec in `Some` if the StaticVec
   /// is not empty, or `None` otherwise.
   #[inline(always)]
@@ -509,6 +904,52 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Returns a constant reference to an array made up of the first `C` elements of the
+  /// StaticVec in `Some` if the StaticVec has a length of at least `C`, or `None` otherwise.
+  #[inline(always)]
+  pub fn first_chunk<const C: usize>(&self) -> Option<&[T; C]> {
+    if self.length < C {
+      None
+    } else {
+      // Safety: `[T; C]` has the same layout as `C` consecutive `T`s, all of which are within
+      // `0..length`.
+      Some(unsafe { &*(self.as_ptr() as *const [T; C]) })
+    }
+  }
+
+  /// Returns a mutable reference to an array made up of the first `C` elements of the
+  /// StaticVec in `Some` if the StaticVec has a length of at least `C`, or `None` otherwise.
+  #[inline(always)]
+  pub fn first_chunk_mut<const C: usize>(&mut self) -> Option<&mut [T; C]> {
+    if self.length < C {
+      None
+    } else {
+      Some(unsafe { &mut *(self.as_mut_ptr() as *mut [T; C]) })
+    }
+  }
+
+  /// Returns a constant reference to an array made up of the last `C` elements of the
+  /// StaticVec in `Some` if the StaticVec has a length of at least `C`, or `None` otherwise.
+  #[inline(always)]
+  pub fn last_chunk<const C: usize>(&self) -> Option<&[T; C]> {
+    if self.length < C {
+      None
+    } else {
+      Some(unsafe { &*(self.ptr_at_unchecked(self.length - C) as *const [T; C]) })
+    }
+  }
+
+  /// Returns a mutable reference to an array made up of the last `C` elements of the
+  /// StaticVec in `Some` if the StaticVec has a length of at least `C`, or `None` otherwise.
+  #[inline(always)]
+  pub fn last_chunk_mut<const C: usize>(&mut self) -> Option<&mut [T; C]> {
+    if self.length < C {
+      None
+    } else {
+      Some(unsafe { &mut *(self.mut_ptr_at_unchecked(self.length - C) as *mut [T; C]) })
+    }
+  }
+
   /// Asserts that `index` is less than the current length of the StaticVec,
   /// and if so removes the value at that position and returns it. Any values
   /// that exist in later positions are shifted to the left.
@@ -524,6 +965,19 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Returns `None` if `index` is greater than or equal to the current length of the StaticVec.
+  /// Otherwise, removes the value at that position and returns it in `Some`, shifting any values
+  /// that exist in later positions to the left exactly as [`remove`](crate::StaticVec::remove)
+  /// does.
+  #[inline]
+  pub fn try_remove(&mut self, index: usize) -> Option<T> {
+    if index < self.length {
+      Some(self.remove(index))
+    } else {
+      None
+    }
+  }
+
   /// Removes the first instance of `item` from the StaticVec if the item exists.
   #[inline(always)]
   pub fn remove_item(&mut self, item: &T) -> Option<T>
@@ -536,91 +990,519 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
-  /// Returns `None` if `index` is greater than or equal to the current length of the StaticVec.
-  /// Otherwise, removes the value at that position and returns it in `Some`, and then
-  /// moves the last value in the StaticVec into the empty slot.
+  /// Removes every element of the StaticVec for which `pred` returns true, and returns them in a
+  /// new StaticVec in the order they originally appeared. This is equivalent to collecting the
+  /// output of [`drain_filter`](crate::StaticVec::drain_filter), except that `pred` only receives
+  /// an immutable reference to each element.
   #[inline(always)]
-  pub fn swap_pop(&mut self, index: usize) -> Option<T> {
-    if index < self.length {
-      unsafe {
-        let last_value = self.data.get_unchecked(self.length - 1).read();
-        self.length -= 1;
-        Some(self.mut_ptr_at_unchecked(index).replace(last_value))
+  pub fn remove_all<P>(&mut self, mut pred: P) -> Self
+  where P: FnMut(&T) -> bool {
+    self.drain_filter(|val| pred(val)).collect()
+  }
+
+  /// Returns a new StaticVec with a capacity of `N2` containing the indices of every element in
+  /// the StaticVec's inhabited area for which `pred` returns true, in ascending order.
+  ///
+  /// Panics if the number of matching elements is greater than `N2`.
+  #[inline]
+  pub fn find_indices<P, const N2: usize>(&self, mut pred: P) -> StaticVec<usize, N2>
+  where P: FnMut(&T) -> bool {
+    let mut res = StaticVec::new();
+    for (i, val) in self.iter().enumerate() {
+      if pred(val) {
+        assert!(
+          res.is_not_full(),
+          "Number of matching elements must be less than or equal to {}!",
+          N2
+        );
+        unsafe { res.push_unchecked(i) };
       }
-    } else {
-      None
     }
+    res
   }
 
-  /// Asserts that `index` is less than the current length of the StaticVec,
-  /// and if so removes the value at that position and returns it, and then
-  /// moves the last value in the StaticVec into the empty slot.
+  /// Returns the index of the last element in the StaticVec's inhabited area for which `pred`
+  /// returns true in `Some`, or `None` if there is no such element. The index is counted from
+  /// the start of the StaticVec, as with [`Iterator::rposition`](core::iter::Iterator::rposition).
   #[inline(always)]
-  pub fn swap_remove(&mut self, index: usize) -> T {
-    assert!(index < self.length);
-    unsafe {
-      let last_value = self.data.get_unchecked(self.length - 1).read();
-      self.length -= 1;
-      self.mut_ptr_at_unchecked(index).replace(last_value)
-    }
+  pub fn rposition<P>(&self, mut pred: P) -> Option<usize>
+  where P: FnMut(&T) -> bool {
+    self.iter().rposition(|val| pred(val))
   }
 
-  /// Asserts that the current length of the StaticVec is less than `N` and that
-  /// `index` is less than the length, and if so inserts `value` at that position.
-  /// Any values that exist in positions after `index` are shifted to the right.
-  #[inline]
-  pub fn insert(&mut self, index: usize, value: T) {
-    assert!(self.length < N && index <= self.length);
-    unsafe {
-      let p = self.mut_ptr_at_unchecked(index);
-      p.copy_to(p.offset(1), self.length - index);
-      p.write(value);
-      self.length += 1;
-    }
+  /// Calls `f` on each element of the StaticVec's inhabited area in order, and returns the first
+  /// non-`None` result, or `None` if `f` returns `None` for every element. No further elements
+  /// are visited after the first `Some`.
+  #[inline(always)]
+  pub fn find_map<U, F>(&self, f: F) -> Option<U>
+  where F: FnMut(&T) -> Option<U> {
+    self.iter().find_map(f)
   }
 
-  /// Inserts `value` at `index` if the current length of the StaticVec is less than `N` and `index`
-  /// is less than the length, or returns a error stating one of the two is not the case otherwise.
-  /// Any values that exist in positions after `index` are shifted to the right.
-  #[inline]
-  pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), &'static str> {
-    if self.length < N && index <= self.length {
-      unsafe {
-        let p = self.mut_ptr_at_unchecked(index);
-        p.copy_to(p.offset(1), self.length - index);
-        p.write(value);
-        self.length += 1;
-        Ok(())
-      }
-    } else {
-      Err("One of `self.length < N` or `index <= self.length` is false!")
-    }
+  /// Returns the number of elements in the StaticVec's inhabited area for which `pred` returns
+  /// true.
+  #[inline(always)]
+  pub fn count<P>(&self, mut pred: P) -> usize
+  where P: FnMut(&T) -> bool {
+    self.iter().filter(|val| pred(val)).count()
   }
 
-  /// Removes all contents from the StaticVec and sets its length back to 0.
+  /// Returns a constant reference to the minimum element of the StaticVec's inhabited area in
+  /// `Some`, or `None` if the StaticVec is empty. If several elements are equally minimum, the
+  /// first one is returned.
+  /// Locally requires that `T` implements [`Ord`](core::cmp::Ord) to make the comparison
+  /// possible.
+  ///
+  /// This is not named `min` as that would be shadowed by [`Ord::min`](core::cmp::Ord::min)
+  /// whenever `T` (and therefore the StaticVec itself) implements [`Ord`](core::cmp::Ord).
   #[inline(always)]
-  pub fn clear(&mut self) {
-    unsafe {
-      ptr::drop_in_place(self.as_mut_slice());
-    }
-    self.length = 0;
+  pub fn min_element(&self) -> Option<&T>
+  where T: Ord {
+    self.iter().min()
   }
 
-  /// Returns a [`StaticVecIterConst`](crate::iterators::StaticVecIterConst) over the StaticVec's
-  /// inhabited area.
+  /// Returns a constant reference to the maximum element of the StaticVec's inhabited area in
+  /// `Some`, or `None` if the StaticVec is empty. If several elements are equally maximum, the
+  /// last one is returned.
+  /// Locally requires that `T` implements [`Ord`](core::cmp::Ord) to make the comparison
+  /// possible.
+  ///
+  /// This is not named `max` for the same reason that
+  /// [`min_element`](crate::StaticVec::min_element) is not named `min`.
   #[inline(always)]
-  pub fn iter<'a>(&'a self) -> StaticVecIterConst<'a, T, N> {
-    StaticVecIterConst {
-      start: self.as_ptr(),
-      end: match intrinsics::size_of::<T>() {
-        0 => (self.as_ptr() as *const u8).wrapping_add(self.length) as *const T,
-        _ => unsafe { self.ptr_at_unchecked(self.length) },
-      },
-      marker: PhantomData,
-    }
+  pub fn max_element(&self) -> Option<&T>
+  where T: Ord {
+    self.iter().max()
   }
 
-  /// Returns a [`StaticVecIterMut`](crate::iterators::StaticVecIterMut) over the StaticVec's
+  /// Returns a constant reference to the element of the StaticVec's inhabited area that is the
+  /// minimum with respect to `compare` in `Some`, or `None` if the StaticVec is empty. If several
+  /// elements are equally minimum, the first one is returned.
+  #[inline(always)]
+  pub fn min_by<F>(&self, mut compare: F) -> Option<&T>
+  where F: FnMut(&T, &T) -> Ordering {
+    self.iter().min_by(|a, b| compare(a, b))
+  }
+
+  /// Returns a constant reference to the element of the StaticVec's inhabited area that is the
+  /// maximum with respect to `compare` in `Some`, or `None` if the StaticVec is empty. If several
+  /// elements are equally maximum, the last one is returned.
+  #[inline(always)]
+  pub fn max_by<F>(&self, mut compare: F) -> Option<&T>
+  where F: FnMut(&T, &T) -> Ordering {
+    self.iter().max_by(|a, b| compare(a, b))
+  }
+
+  /// Returns a constant reference to the element of the StaticVec's inhabited area that gives the
+  /// minimum value from `f` in `Some`, or `None` if the StaticVec is empty. If several elements
+  /// are equally minimum, the first one is returned.
+  #[inline(always)]
+  pub fn min_by_key<K, F>(&self, mut f: F) -> Option<&T>
+  where
+    K: Ord,
+    F: FnMut(&T) -> K, {
+    self.iter().min_by_key(|val| f(val))
+  }
+
+  /// Returns a constant reference to the element of the StaticVec's inhabited area that gives the
+  /// maximum value from `f` in `Some`, or `None` if the StaticVec is empty. If several elements
+  /// are equally maximum, the last one is returned.
+  #[inline(always)]
+  pub fn max_by_key<K, F>(&self, mut f: F) -> Option<&T>
+  where
+    K: Ord,
+    F: FnMut(&T) -> K, {
+    self.iter().max_by_key(|val| f(val))
+  }
+
+  /// Sums the elements of the StaticVec's inhabited area into an `S`, in the same way as
+  /// [`Iterator::sum`](core::iter::Iterator::sum) does. The result type usually needs to be
+  /// specified, for example with `v.sum::<i64>()`.
+  #[inline(always)]
+  pub fn sum<'a, S>(&'a self) -> S
+  where S: iter::Sum<&'a T> {
+    self.iter().sum()
+  }
+
+  /// Multiplies the elements of the StaticVec's inhabited area together into a `P`, in the same
+  /// way as [`Iterator::product`](core::iter::Iterator::product) does.
+  #[inline(always)]
+  pub fn product<'a, P>(&'a self) -> P
+  where P: iter::Product<&'a T> {
+    self.iter().product()
+  }
+
+  /// Returns a mutable reference to the first element of the StaticVec for which `key_matches`
+  /// returns true, or pushes the result of calling `make` to the StaticVec and returns a mutable
+  /// reference to that if there is no such element. This is intended to make it easy to use a
+  /// StaticVec of key-value pairs as a small association list.
+  ///
+  /// Panics if no matching element is found and the StaticVec is full.
+  #[inline]
+  pub fn get_or_insert_with<P, F>(&mut self, key_matches: P, make: F) -> &mut T
+  where
+    P: Fn(&T) -> bool,
+    F: FnOnce() -> T, {
+    let index = match self.iter().position(key_matches) {
+      Some(index) => index,
+      None => {
+        self.push(make());
+        self.length - 1
+      }
+    };
+    unsafe { self.get_unchecked_mut(index) }
+  }
+
+  /// Returns the index at which the first occurrence of `needle` as a contiguous run of elements
+  /// begins in the StaticVec's inhabited area, or `None` if there is no such occurrence. An empty
+  /// `needle` is always found at index 0. For `StaticVec<u8, N>`, candidate positions are located
+  /// using the same word-at-a-time search as [`find_byte`](crate::StaticVec::find_byte).
+  /// Locally requires that `T` implements [`PartialEq`](core::cmp::PartialEq) to make the
+  /// comparison possible.
+  #[inline(always)]
+  pub fn find_subslice(&self, needle: &[T]) -> Option<usize>
+  where T: PartialEq {
+    T::find_subslice(self, needle)
+  }
+
+  /// Returns true if `needle` occurs as a contiguous run of elements anywhere in the StaticVec's
+  /// inhabited area, as determined by [`find_subslice`](crate::StaticVec::find_subslice).
+  /// Locally requires that `T` implements [`PartialEq`](core::cmp::PartialEq) to make the
+  /// comparison possible.
+  #[inline(always)]
+  pub fn contains_subslice(&self, needle: &[T]) -> bool
+  where T: PartialEq {
+    self.find_subslice(needle).is_some()
+  }
+
+  /// Returns true if `prefix` is a prefix of the StaticVec's inhabited area (which is always the
+  /// case for an empty `prefix`).
+  /// Locally requires that `T` implements [`PartialEq`](core::cmp::PartialEq) to make the
+  /// comparison possible.
+  #[inline(always)]
+  pub fn starts_with(&self, prefix: &[T]) -> bool
+  where T: PartialEq {
+    self.as_slice().starts_with(prefix)
+  }
+
+  /// Returns true if `suffix` is a suffix of the StaticVec's inhabited area (which is always the
+  /// case for an empty `suffix`).
+  /// Locally requires that `T` implements [`PartialEq`](core::cmp::PartialEq) to make the
+  /// comparison possible.
+  #[inline(always)]
+  pub fn ends_with(&self, suffix: &[T]) -> bool
+  where T: PartialEq {
+    self.as_slice().ends_with(suffix)
+  }
+
+  /// Returns true if `item` is present in the StaticVec's inhabited area.
+  /// Locally requires that `T` implements [`PartialEq`](core::cmp::PartialEq) to make the
+  /// comparison possible.
+  #[inline(always)]
+  pub fn contains(&self, item: &T) -> bool
+  where T: PartialEq {
+    self.iter().any(|x| *x == *item)
+  }
+
+  /// Binary searches the StaticVec's inhabited area (which is assumed to be sorted) for `x`.
+  /// If the value is found, its index is returned in `Ok`. If it is not found, the index at which
+  /// it could be inserted while maintaining sorted order is returned in `Err`. Locally requires
+  /// that `T` implements [`Ord`](core::cmp::Ord) to make the comparison possible.
+  #[inline(always)]
+  pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+  where T: Ord {
+    self.as_slice().binary_search(x)
+  }
+
+  /// Binary searches the StaticVec's inhabited area (which is assumed to be sorted) using a
+  /// comparator function that indicates whether its argument is `Less`, `Equal` or `Greater`
+  /// than the desired target. The return value is interpreted in the same way as it is for
+  /// [`binary_search`](crate::StaticVec::binary_search).
+  #[inline(always)]
+  pub fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>
+  where F: FnMut(&'a T) -> Ordering {
+    self.as_slice().binary_search_by(f)
+  }
+
+  /// Binary searches the StaticVec's inhabited area (which is assumed to be sorted by the key in
+  /// question) for `b`, using a key extraction function. The return value is interpreted in the
+  /// same way as it is for [`binary_search`](crate::StaticVec::binary_search).
+  #[inline(always)]
+  pub fn binary_search_by_key<'a, B, F>(&'a self, b: &B, f: F) -> Result<usize, usize>
+  where
+    F: FnMut(&'a T) -> B,
+    B: Ord, {
+    self.as_slice().binary_search_by_key(b, f)
+  }
+
+  /// Returns the index of the partition point of the StaticVec's inhabited area according to
+  /// `pred`, that is, the index of the first element for which `pred` returns false. The
+  /// StaticVec is assumed to be partitioned such that every element for which `pred` returns
+  /// true comes before every element for which it returns false. If `pred` returns true for every
+  /// element, the length of the StaticVec is returned.
+  #[inline]
+  pub fn partition_point<P>(&self, mut pred: P) -> usize
+  where P: FnMut(&T) -> bool {
+    self
+      .binary_search_by(|x| {
+        if pred(x) {
+          Ordering::Less
+        } else {
+          Ordering::Greater
+        }
+      })
+      .unwrap_or_else(|i| i)
+  }
+
+  /// Inserts `value` into the StaticVec at the position that keeps it sorted, assuming it already
+  /// was, and returns that position in `Ok`. If there are elements equal to `value`, it is
+  /// inserted after all of them, so values that compare equal keep their insertion order. Returns
+  /// an error, without modifying the StaticVec, if it is already full.
+  /// Locally requires that `T` implements [`Ord`](core::cmp::Ord) to make the comparisons
+  /// possible.
+  #[inline(always)]
+  pub fn binary_insert(&mut self, value: T) -> Result<usize, &'static str>
+  where T: Ord {
+    self.binary_insert_by(value, |a, b| a.cmp(b))
+  }
+
+  /// Inserts `value` into the StaticVec exactly as
+  /// [`binary_insert`](crate::StaticVec::binary_insert) does, but using the comparator function
+  /// `compare`, which is called with an element of the StaticVec as its first argument and
+  /// `value` as its second.
+  #[inline]
+  pub fn binary_insert_by<F>(&mut self, value: T, mut compare: F) -> Result<usize, &'static str>
+  where F: FnMut(&T, &T) -> Ordering {
+    if self.is_full() {
+      return Err("Insufficient remaining capacity!");
+    }
+    let index = self.partition_point(|x| compare(x, &value) != Ordering::Greater);
+    self.insert(index, value);
+    Ok(index)
+  }
+
+  /// Inserts `value` into the StaticVec exactly as
+  /// [`binary_insert`](crate::StaticVec::binary_insert) does, but comparing the keys extracted
+  /// from each element by `key`.
+  #[inline(always)]
+  pub fn binary_insert_by_key<K, F>(
+    &mut self,
+    value: T,
+    mut key: F,
+  ) -> Result<usize, &'static str>
+  where
+    K: Ord,
+    F: FnMut(&T) -> K,
+  {
+    self.binary_insert_by(value, |a, b| key(a).cmp(&key(b)))
+  }
+
+  /// Returns `None` if `index` is greater than or equal to the current length of the StaticVec.
+  /// Otherwise, removes the value at that position and returns it in `Some`, and then
+  /// moves the last value in the StaticVec into the empty slot.
+  #[inline(always)]
+  pub fn swap_pop(&mut self, index: usize) -> Option<T> {
+    if index < self.length {
+      unsafe {
+        let last_value = self.data.get_unchecked(self.length - 1).read();
+        self.length -= 1;
+        Some(self.mut_ptr_at_unchecked(index).replace(last_value))
+      }
+    } else {
+      None
+    }
+  }
+
+  /// Asserts that `index` is less than the current length of the StaticVec,
+  /// and if so removes the value at that position and returns it, and then
+  /// moves the last value in the StaticVec into the empty slot.
+  #[inline(always)]
+  pub fn swap_remove(&mut self, index: usize) -> T {
+    assert!(index < self.length);
+    unsafe {
+      let last_value = self.data.get_unchecked(self.length - 1).read();
+      self.length -= 1;
+      self.mut_ptr_at_unchecked(index).replace(last_value)
+    }
+  }
+
+  /// Returns `None` if `index` is greater than or equal to the current length of the StaticVec.
+  /// Otherwise, removes the value at that position and returns it in `Some`, and then moves the
+  /// last value in the StaticVec into the empty slot. This is the non-panicking counterpart to
+  /// [`swap_remove`](crate::StaticVec::swap_remove), and is exactly equivalent to
+  /// [`swap_pop`](crate::StaticVec::swap_pop).
+  #[inline(always)]
+  pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+    self.swap_pop(index)
+  }
+
+  /// Asserts that both `a` and `b` are less than the current length of the StaticVec,
+  /// and if so swaps the values at those two positions. Does nothing if `a` and `b` are equal.
+  #[inline(always)]
+  pub fn swap(&mut self, a: usize, b: usize) {
+    assert!(
+      a < self.length && b < self.length,
+      "Provided indices {} and {} must both be between 0 and {}!",
+      a,
+      b,
+      self.length
+    );
+    unsafe { self.swap_unchecked(a, b) }
+  }
+
+  /// Swaps the values at positions `a` and `b` in the StaticVec without doing any checking to
+  /// ensure that both are within the range `0..length`. Does nothing if `a` and `b` are equal.
+  ///
+  /// # Safety
+  ///
+  /// It is up to the caller to ensure that both `a` and `b` are within the appropriate bounds.
+  #[inline(always)]
+  pub unsafe fn swap_unchecked(&mut self, a: usize, b: usize) {
+    debug_assert!(
+      a < self.length && b < self.length,
+      "Attempted to unsafely swap indices {} and {} when length is {}!",
+      a,
+      b,
+      self.length
+    );
+    let ptr = self.as_mut_ptr();
+    ptr::swap(ptr.add(a), ptr.add(b));
+  }
+
+  /// Asserts that the current length of the StaticVec is less than `N` and that
+  /// `index` is less than the length, and if so inserts `value` at that position.
+  /// Any values that exist in positions after `index` are shifted to the right.
+  #[inline]
+  pub fn insert(&mut self, index: usize, value: T) {
+    assert!(self.length < N && index <= self.length);
+    unsafe {
+      let p = self.mut_ptr_at_unchecked(index);
+      p.copy_to(p.offset(1), self.length - index);
+      p.write(value);
+      self.length += 1;
+    }
+  }
+
+  /// Inserts `value` at `index` if the current length of the StaticVec is less than `N` and `index`
+  /// is less than the length, or returns a error stating one of the two is not the case otherwise.
+  /// Any values that exist in positions after `index` are shifted to the right.
+  #[inline]
+  pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), &'static str> {
+    if self.length < N && index <= self.length {
+      unsafe {
+        let p = self.mut_ptr_at_unchecked(index);
+        p.copy_to(p.offset(1), self.length - index);
+        p.write(value);
+        self.length += 1;
+        Ok(())
+      }
+    } else {
+      Err("One of `self.length < N` or `index <= self.length` is false!")
+    }
+  }
+
+  /// Asserts that the current length of the StaticVec plus the length of `other` is less than or
+  /// equal to `N` and that `index` is less than or equal to the length, and if so copies all of
+  /// the elements of `other` into the StaticVec starting at `index`. Any values that exist in
+  /// positions after `index` are shifted to the right just once, making this much faster than
+  /// calling [`insert`](crate::StaticVec::insert) repeatedly.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn insert_from_slice(&mut self, index: usize, other: &[T])
+  where T: Copy {
+    assert!(
+      self.length + other.len() <= N && index <= self.length,
+      "One of `self.length + other.len() <= N` or `index <= self.length` is false!"
+    );
+    unsafe { self.insert_from_slice_unchecked(index, other) }
+  }
+
+  /// Copies all of the elements of `other` into the StaticVec starting at `index` if the current
+  /// length of the StaticVec plus the length of `other` is less than or equal to `N` and `index`
+  /// is less than or equal to the length, or returns an error stating one of the two is not the
+  /// case otherwise. Any values that exist in positions after `index` are shifted to the right.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn try_insert_from_slice(&mut self, index: usize, other: &[T]) -> Result<(), &'static str>
+  where T: Copy {
+    if self.length + other.len() <= N && index <= self.length {
+      unsafe { self.insert_from_slice_unchecked(index, other) };
+      Ok(())
+    } else {
+      Err("One of `self.length + other.len() <= N` or `index <= self.length` is false!")
+    }
+  }
+
+  /// Asserts that `index` is less than or equal to the current length of the StaticVec, and if
+  /// so inserts all of the items from `iter` at that position, in order. Any values that exist in
+  /// positions after `index` are shifted to the right just once, after all of the items have been
+  /// collected into a temporary buffer. Unlike
+  /// [`insert_from_slice`](crate::StaticVec::insert_from_slice), this does not require that `T`
+  /// implements [`Copy`](core::marker::Copy).
+  ///
+  /// Panics if `iter` yields more items than the StaticVec has remaining capacity for, in which
+  /// case the StaticVec is left unmodified.
+  #[inline]
+  pub fn insert_many<I>(&mut self, index: usize, iter: I)
+  where I: IntoIterator<Item = T> {
+    assert!(
+      index <= self.length,
+      "Provided index {} must be between 0 and {}!",
+      index,
+      self.length
+    );
+    let mut buffer = Self::new();
+    for item in iter {
+      assert!(
+        self.length + buffer.length < N,
+        "Insufficient remaining capacity!"
+      );
+      unsafe { buffer.push_unchecked(item) };
+    }
+    unsafe {
+      self.insert_from_slice_unchecked(index, &buffer);
+      // The items now belong to `self`.
+      buffer.set_len(0);
+    }
+  }
+
+  #[inline(always)]
+  unsafe fn insert_from_slice_unchecked(&mut self, index: usize, other: &[T]) {
+    // Internal shared implementation of `insert_from_slice`, `try_insert_from_slice`, and
+    // `insert_many`. The elements of `other` are moved bitwise, so the caller is responsible for
+    // making sure they are not dropped twice if `T` is not `Copy`. `other` can't alias `self`
+    // here, as we have a unique reference to it.
+    let added_length = other.len();
+    let p = self.mut_ptr_at_unchecked(index);
+    p.copy_to(p.add(added_length), self.length - index);
+    other.as_ptr().copy_to_nonoverlapping(p, added_length);
+    self.length += added_length;
+  }
+
+  /// Removes all contents from the StaticVec and sets its length back to 0.
+  #[inline(always)]
+  pub fn clear(&mut self) {
+    unsafe {
+      ptr::drop_in_place(self.as_mut_slice());
+    }
+    self.length = 0;
+  }
+
+  /// Returns a [`StaticVecIterConst`](crate::iterators::StaticVecIterConst) over the StaticVec's
+  /// inhabited area.
+  #[inline(always)]
+  pub fn iter<'a>(&'a self) -> StaticVecIterConst<'a, T, N> {
+    StaticVecIterConst {
+      start: self.as_ptr(),
+      end: match intrinsics::size_of::<T>() {
+        0 => (self.as_ptr() as *const u8).wrapping_add(self.length) as *const T,
+        _ => unsafe { self.ptr_at_unchecked(self.length) },
+      },
+      marker: PhantomData,
+    }
+  }
+
+  /// Returns a [`StaticVecIterMut`](crate::iterators::StaticVecIterMut) over the StaticVec's
   /// inhabited area.
   #[inline(always)]
   pub fn iter_mut<'a>(&'a mut self) -> StaticVecIterMut<'a, T, N> {
@@ -634,6 +1516,308 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Returns an iterator over `chunk_size` elements of the StaticVec's inhabited area at a time,
+  /// starting from the beginning. The last chunk will be shorter than `chunk_size` if the length
+  /// of the StaticVec is not evenly divisible by it. Equivalent to `self.as_slice().chunks()`.
+  ///
+  /// Panics if `chunk_size` is 0.
+  #[inline(always)]
+  pub fn chunks(&self, chunk_size: usize) -> slice::Chunks<T> {
+    self.as_slice().chunks(chunk_size)
+  }
+
+  /// Returns an iterator over mutable chunks of `chunk_size` elements of the StaticVec's inhabited
+  /// area at a time, with the same behavior as [`chunks`](crate::StaticVec::chunks).
+  ///
+  /// Panics if `chunk_size` is 0.
+  #[inline(always)]
+  pub fn chunks_mut(&mut self, chunk_size: usize) -> slice::ChunksMut<T> {
+    self.as_mut_slice().chunks_mut(chunk_size)
+  }
+
+  /// Calls `f` on each non-overlapping mutable chunk of `chunk_size` elements of the StaticVec's
+  /// inhabited area in order, starting from the beginning. The final chunk passed to `f` will be
+  /// shorter than `chunk_size` if the length of the StaticVec is not evenly divisible by it.
+  ///
+  /// Panics if `chunk_size` is 0.
+  #[inline]
+  pub fn for_each_chunk_mut<F>(&mut self, chunk_size: usize, f: F)
+  where F: FnMut(&mut [T]) {
+    assert!(chunk_size != 0, "Chunk size must be greater than 0!");
+    self.as_mut_slice().chunks_mut(chunk_size).for_each(f);
+  }
+
+  /// Returns an iterator over exactly `chunk_size` elements of the StaticVec's inhabited area at a
+  /// time. If the length of the StaticVec is not evenly divisible by `chunk_size`, the leftover
+  /// elements are not yielded, and can instead be retrieved from the iterator's `remainder`
+  /// method. Equivalent to `self.as_slice().chunks_exact()`.
+  ///
+  /// Panics if `chunk_size` is 0.
+  #[inline(always)]
+  pub fn chunks_exact(&self, chunk_size: usize) -> slice::ChunksExact<T> {
+    self.as_slice().chunks_exact(chunk_size)
+  }
+
+  /// Returns an iterator over `chunk_size` elements of the StaticVec's inhabited area at a time,
+  /// starting from the end. The last chunk yielded (that is, the one at the start of the
+  /// StaticVec) will be shorter than `chunk_size` if the length of the StaticVec is not evenly
+  /// divisible by it. Equivalent to `self.as_slice().rchunks()`.
+  ///
+  /// Panics if `chunk_size` is 0.
+  #[inline(always)]
+  pub fn rchunks(&self, chunk_size: usize) -> slice::RChunks<T> {
+    self.as_slice().rchunks(chunk_size)
+  }
+
+  /// Returns an iterator over mutable chunks of `chunk_size` elements of the StaticVec's inhabited
+  /// area at a time, with the same behavior as [`rchunks`](crate::StaticVec::rchunks).
+  ///
+  /// Panics if `chunk_size` is 0.
+  #[inline(always)]
+  pub fn rchunks_mut(&mut self, chunk_size: usize) -> slice::RChunksMut<T> {
+    self.as_mut_slice().rchunks_mut(chunk_size)
+  }
+
+  /// Returns a [`StaticVecWindowsMut`](crate::iterators::StaticVecWindowsMut) over all
+  /// contiguous mutable windows of length `size` in the StaticVec's inhabited area, which
+  /// overlap just as those yielded by [`windows`](slice::Windows) do. Each window must be let go
+  /// of before the next one is requested, which the borrow checker enforces.
+  ///
+  /// Panics if `size` is 0.
+  ///
+  /// Example usage:
+  /// ```
+  /// let mut v = staticvec![1, 1, 0, 0, 0];
+  /// let mut windows = v.windows_mut(3);
+  /// while let Some(window) = windows.next() {
+  ///   window[2] = window[0] + window[1];
+  /// }
+  /// assert_eq!(v, [1, 1, 2, 3, 5]);
+  /// ```
+  #[inline]
+  pub fn windows_mut(&mut self, size: usize) -> StaticVecWindowsMut<T> {
+    assert!(size != 0, "Window size must be greater than 0!");
+    StaticVecWindowsMut {
+      slice: self.as_mut_slice(),
+      size,
+      position: 0,
+    }
+  }
+
+  /// Returns a [`StaticVecArrayChunks`](crate::iterators::StaticVecArrayChunks) over `C`
+  /// elements of the StaticVec's inhabited area at a time, starting from the beginning, as
+  /// references to arrays of length `C`. If the length of the StaticVec is not evenly divisible
+  /// by `C`, the leftover elements at the end are not yielded, and can instead be retrieved from
+  /// the iterator's [`remainder`](crate::iterators::StaticVecArrayChunks::remainder) method.
+  ///
+  /// Panics if `C` is 0.
+  ///
+  /// Example usage:
+  /// ```
+  /// let v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  /// let chunks: StaticVecArrayChunks<i32, 3> = v.array_chunks();
+  /// assert_eq!(chunks.remainder(), [7]);
+  /// for &[a, b, c] in chunks {
+  ///   assert_eq!(a + 1, b);
+  ///   assert_eq!(b + 1, c);
+  /// }
+  /// ```
+  #[inline]
+  pub fn array_chunks<const C: usize>(&self) -> StaticVecArrayChunks<T, C> {
+    assert!(C != 0, "Chunk size must be greater than 0!");
+    let chunk_count = self.length / C;
+    // Safety: `[T; C]` has the same layout as `C` consecutive `T`s, and we only go as far as the
+    // last complete chunk within `0..length`.
+    unsafe {
+      StaticVecArrayChunks {
+        iter: slice::from_raw_parts(self.as_ptr() as *const [T; C], chunk_count).iter(),
+        remainder: self.as_slice().get_unchecked(chunk_count * C..),
+      }
+    }
+  }
+
+  /// Splits the StaticVec's inhabited area into a mutable slice of as many `[T; C]` arrays as will
+  /// fit, starting from the beginning, and a mutable slice of the leftover `length % C` elements
+  /// at the end. Operating on the arrays rather than on the elements individually can make it
+  /// much easier for the compiler to vectorize a loop.
+  ///
+  /// Panics if `C` is 0.
+  #[inline]
+  pub fn as_chunks_mut<const C: usize>(&mut self) -> (&mut [[T; C]], &mut [T]) {
+    assert!(C != 0, "Chunk size must be greater than 0!");
+    let chunk_count = self.length / C;
+    let chunked_length = chunk_count * C;
+    let remainder_length = self.length - chunked_length;
+    let p = self.as_mut_ptr();
+    // Safety: see `array_chunks`. The two slices are disjoint, and both lie within `0..length`.
+    unsafe {
+      (
+        slice::from_raw_parts_mut(p as *mut [T; C], chunk_count),
+        slice::from_raw_parts_mut(p.add(chunked_length), remainder_length),
+      )
+    }
+  }
+
+  /// Returns an iterator over every overlapping window of `size` contiguous elements of the
+  /// StaticVec's inhabited area. Yields nothing if the length of the StaticVec is less than
+  /// `size`. Equivalent to `self.as_slice().windows()`.
+  ///
+  /// Panics if `size` is 0.
+  #[inline(always)]
+  pub fn windows(&self, size: usize) -> slice::Windows<T> {
+    self.as_slice().windows(size)
+  }
+
+  /// Returns an iterator over the subslices of the StaticVec's inhabited area separated by
+  /// elements that match `pred`, starting from the end. The matched elements are not contained in
+  /// the subslices. Equivalent to `self.as_slice().rsplit()`.
+  #[inline(always)]
+  pub fn rsplit<F>(&self, pred: F) -> slice::RSplit<T, F>
+  where F: FnMut(&T) -> bool {
+    self.as_slice().rsplit(pred)
+  }
+
+  /// Returns an iterator over at most `n` subslices of the StaticVec's inhabited area separated by
+  /// elements that match `pred`, starting from the beginning. The last subslice yielded contains
+  /// the entire remainder of the StaticVec. Equivalent to `self.as_slice().splitn()`.
+  #[inline(always)]
+  pub fn splitn<F>(&self, n: usize, pred: F) -> slice::SplitN<T, F>
+  where F: FnMut(&T) -> bool {
+    self.as_slice().splitn(n, pred)
+  }
+
+  /// Returns an iterator over at most `n` subslices of the StaticVec's inhabited area separated by
+  /// elements that match `pred`, starting from the end. The last subslice yielded contains the
+  /// entire remainder of the StaticVec. Equivalent to `self.as_slice().rsplitn()`.
+  #[inline(always)]
+  pub fn rsplitn<F>(&self, n: usize, pred: F) -> slice::RSplitN<T, F>
+  where F: FnMut(&T) -> bool {
+    self.as_slice().rsplitn(n, pred)
+  }
+
+  /// Splits the StaticVec's inhabited area at each element for which `pred` returns true, and
+  /// returns copies of the resulting subslices (not including the matched elements) as up to
+  /// `PARTS` StaticVecs with a capacity of `CAP` each. As with
+  /// [`str::split`](https://doc.rust-lang.org/nightly/std/primitive.str.html#method.split),
+  /// adjacent separators produce empty parts, and an empty StaticVec produces a single empty
+  /// part.
+  ///
+  /// Panics if there are more than `PARTS` parts, or if any part is longer than `CAP`.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn split_when<P, const PARTS: usize, const CAP: usize>(
+    &self,
+    mut pred: P,
+  ) -> StaticVec<StaticVec<T, CAP>, PARTS>
+  where
+    P: FnMut(&T) -> bool,
+    T: Copy,
+  {
+    let mut res = StaticVec::new();
+    for part in self.as_slice().split(|val| pred(val)) {
+      assert!(
+        res.is_not_full(),
+        "Number of parts must be less than or equal to {}!",
+        PARTS
+      );
+      assert!(
+        part.len() <= CAP,
+        "Part length {} must be less than or equal to {}!",
+        part.len(),
+        CAP
+      );
+      unsafe { res.push_unchecked(StaticVec::new_from_slice(part)) };
+    }
+    res
+  }
+
+  /// Returns an iterator over the StaticVec's inhabited area that yields maximal subslices of
+  /// consecutive elements for which `pred` returns true when called on each adjacent pair. For
+  /// example, using `==` as the predicate groups the StaticVec into runs of equal elements.
+  #[inline]
+  pub fn group_by<'a, F>(&'a self, mut pred: F) -> impl Iterator<Item = &'a [T]> + 'a
+  where F: FnMut(&T, &T) -> bool + 'a {
+    let mut rest = self.as_slice();
+    iter::from_fn(move || {
+      if rest.is_empty() {
+        return None;
+      }
+      let mut group_length = 1;
+      while group_length < rest.len() && pred(&rest[group_length - 1], &rest[group_length]) {
+        group_length += 1;
+      }
+      let (group, remaining) = rest.split_at(group_length);
+      rest = remaining;
+      Some(group)
+    })
+  }
+
+  /// Returns an iterator over each pair of adjacent elements in the StaticVec's inhabited area, as
+  /// `(&self[i], &self[i + 1])` tuples. This is equivalent to
+  /// [`windows(2)`](slice::Windows), but yields tuples that can be destructured directly.
+  /// Nothing is yielded if the StaticVec has fewer than two elements.
+  #[inline(always)]
+  pub fn pairs(&self) -> impl Iterator<Item = (&T, &T)> {
+    self.iter().zip(self.iter().skip(1))
+  }
+
+  /// Stable-sorts the StaticVec's inhabited area in place.
+  /// Locally requires that `T` implements [`Ord`](core::cmp::Ord) to make the sorting possible.
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline(always)]
+  pub fn sort(&mut self)
+  where T: Ord {
+    self.as_mut_slice().sort()
+  }
+
+  /// Stable-sorts the StaticVec's inhabited area in place using the comparator function
+  /// `compare`.
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline(always)]
+  pub fn sort_by<F>(&mut self, compare: F)
+  where F: FnMut(&T, &T) -> Ordering {
+    self.as_mut_slice().sort_by(compare)
+  }
+
+  /// Stable-sorts the StaticVec's inhabited area in place using the key extraction function `f`.
+  #[cfg(feature = "std")]
+  #[doc(cfg(feature = "std"))]
+  #[inline(always)]
+  pub fn sort_by_key<K, F>(&mut self, f: F)
+  where
+    F: FnMut(&T) -> K,
+    K: Ord, {
+    self.as_mut_slice().sort_by_key(f)
+  }
+
+  /// Unstable-sorts the StaticVec's inhabited area in place.
+  /// Locally requires that `T` implements [`Ord`](core::cmp::Ord) to make the sorting possible.
+  #[inline(always)]
+  pub fn sort_unstable(&mut self)
+  where T: Ord {
+    self.as_mut_slice().sort_unstable()
+  }
+
+  /// Unstable-sorts the StaticVec's inhabited area in place using the comparator function
+  /// `compare`.
+  #[inline(always)]
+  pub fn sort_unstable_by<F>(&mut self, compare: F)
+  where F: FnMut(&T, &T) -> Ordering {
+    self.as_mut_slice().sort_unstable_by(compare)
+  }
+
+  /// Unstable-sorts the StaticVec's inhabited area in place using the key extraction function
+  /// `f`.
+  #[inline(always)]
+  pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
+  where
+    F: FnMut(&T) -> K,
+    K: Ord, {
+    self.as_mut_slice().sort_unstable_by_key(f)
+  }
+
   /// Returns a separate, stable-sorted StaticVec of the contents of the
   /// StaticVec's inhabited area without modifying the original data.
   /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues,
@@ -660,6 +1844,43 @@ impl<T, const N: usize> StaticVec<T, N> {
     res
   }
 
+  /// Unstable-sorts the StaticVec's inhabited area in place and then removes all consecutive
+  /// repeated elements, leaving each distinct value exactly once in ascending order.
+  /// Locally requires that `T` implements [`Ord`](core::cmp::Ord) to make the sorting possible.
+  #[inline]
+  pub fn sort_and_dedup(&mut self)
+  where T: Ord {
+    self.sort_unstable();
+    self.dedup();
+  }
+
+  /// Returns a separate StaticVec with a capacity of `N2` containing each distinct value from the
+  /// StaticVec's inhabited area exactly once in ascending order, without modifying the original
+  /// data. Panics if the number of distinct values is greater than `N2`.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues,
+  /// and [`Ord`](core::cmp::Ord) to make the sorting possible.
+  #[inline]
+  pub fn to_sorted_unique<const N2: usize>(&self) -> StaticVec<T, N2>
+  where T: Copy + Ord {
+    let mut tmp = self.clone();
+    tmp.sort_and_dedup();
+    assert!(
+      tmp.length <= N2,
+      "Number of unique values {} must be less than or equal to {}!",
+      tmp.length,
+      N2
+    );
+    StaticVec::new_from_slice(&tmp)
+  }
+
+  /// Reverses the order of the elements in the StaticVec's inhabited area in place. Unlike
+  /// [`reversed`](crate::StaticVec::reversed), this does not require that `T` implements
+  /// [`Copy`](core::marker::Copy).
+  #[inline(always)]
+  pub fn reverse(&mut self) {
+    self.as_mut_slice().reverse()
+  }
+
   /// Returns a separate, reversed StaticVec of the contents of the StaticVec's
   /// inhabited area without modifying the original data.
   /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
@@ -680,6 +1901,59 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Returns a separate StaticVec containing clones of the contents of the StaticVec's inhabited
+  /// area, without modifying the original data. Unlike the various methods that produce a copy
+  /// (such as [`reversed`](crate::StaticVec::reversed)), this only requires that `T` implements
+  /// [`Clone`](core::clone::Clone). It is exactly equivalent to calling
+  /// [`clone_from_slice_new`](crate::StaticVec::clone_from_slice_new) on the StaticVec.
+  #[inline(always)]
+  pub fn cloned(&self) -> Self
+  where T: Clone {
+    Self::clone_from_slice_new(self)
+  }
+
+  /// Returns a new StaticVec with the same capacity as this one, containing the results of
+  /// calling `f` on a reference to each element of the StaticVec's inhabited area, in order.
+  #[inline]
+  pub fn mapped<U, F>(&self, mut f: F) -> StaticVec<U, N>
+  where F: FnMut(&T) -> U {
+    let mut res = StaticVec::new();
+    for item in self.iter() {
+      // Safety: `res` has the same capacity as `self`, so it can't be full here.
+      unsafe { res.push_unchecked(f(item)) };
+    }
+    res
+  }
+
+  /// Consumes the StaticVec, returning a new one with the same capacity containing the results of
+  /// calling `f` on each of its elements by value, in order. If `f` panics, every element not
+  /// yet passed to it is still dropped properly.
+  #[inline]
+  pub fn into_mapped<U, F>(self, mut f: F) -> StaticVec<U, N>
+  where F: FnMut(T) -> U {
+    let mut res = StaticVec::new();
+    for item in self {
+      unsafe { res.push_unchecked(f(item)) };
+    }
+    res
+  }
+
+  /// Rotates the StaticVec's inhabited area in-place such that the first `mid` elements move to
+  /// the end while the remaining `length - mid` elements move to the front.
+  /// Panics if `mid` is greater than the current length of the StaticVec.
+  #[inline(always)]
+  pub fn rotate_left(&mut self, mid: usize) {
+    self.as_mut_slice().rotate_left(mid)
+  }
+
+  /// Rotates the StaticVec's inhabited area in-place such that the last `k` elements move to
+  /// the front while the remaining `length - k` elements move to the end.
+  /// Panics if `k` is greater than the current length of the StaticVec.
+  #[inline(always)]
+  pub fn rotate_right(&mut self, k: usize) {
+    self.as_mut_slice().rotate_right(k)
+  }
+
   /// Copies and appends all elements, if any, of a slice (which can also be `&mut` as it will
   /// coerce implicitly to `&`) to the StaticVec. If the slice has a length greater than the
   /// StaticVec's remaining capacity, any contents after that point are ignored.
@@ -697,6 +1971,19 @@ impl<T, const N: usize> StaticVec<T, N> {
     self.length += added_length;
   }
 
+  /// Copies and appends as many elements of a slice to the StaticVec as will fit, exactly as
+  /// [`extend_from_slice`](crate::StaticVec::extend_from_slice) does, and returns the number of
+  /// elements at the end of the slice that were left out due to insufficient remaining capacity
+  /// (which is 0 if the whole slice was appended.)
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline(always)]
+  pub fn saturating_extend_from_slice(&mut self, other: &[T]) -> usize
+  where T: Copy {
+    let old_length = self.length;
+    self.extend_from_slice(other);
+    other.len() - (self.length - old_length)
+  }
+
   /// Copies and appends all elements, if any, of a slice to the StaticVec if the
   /// StaticVec's remaining capacity is greater than the length of the slice, or returns
   /// an error indicating that's not the case otherwise.
@@ -716,6 +2003,157 @@ impl<T, const N: usize> StaticVec<T, N> {
     Ok(())
   }
 
+  /// Returns a new StaticVec with a capacity of `N2` containing the contents of every slice-like
+  /// element of the StaticVec, one after another, such as when flattening a StaticVec of
+  /// StaticVecs.
+  ///
+  /// Panics if the combined length of the elements is greater than `N2`.
+  ///
+  /// Locally requires that `T` implements [`AsRef<[U]>`](core::convert::AsRef), and that `U`
+  /// implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn concat<U, const N2: usize>(&self) -> StaticVec<U, N2>
+  where
+    T: AsRef<[U]>,
+    U: Copy, {
+    let total_length = self.iter().map(|item| item.as_ref().len()).sum::<usize>();
+    assert!(
+      total_length <= N2,
+      "Combined length {} must be less than or equal to {}!",
+      total_length,
+      N2
+    );
+    let mut res = StaticVec::new();
+    for item in self.iter() {
+      res.extend_from_slice(item.as_ref());
+    }
+    res
+  }
+
+  /// Returns a new StaticVec with a capacity of `N2` containing the contents of every slice-like
+  /// element of the StaticVec, one after another, with the contents of `separator` placed between
+  /// each of them.
+  ///
+  /// Panics if the combined length of the elements and separators is greater than `N2`.
+  ///
+  /// Locally requires that `T` implements [`AsRef<[U]>`](core::convert::AsRef), and that `U`
+  /// implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn join<U, const N2: usize>(&self, separator: &[U]) -> StaticVec<U, N2>
+  where
+    T: AsRef<[U]>,
+    U: Copy, {
+    let total_length = self.iter().map(|item| item.as_ref().len()).sum::<usize>()
+      + separator.len() * self.length.saturating_sub(1);
+    assert!(
+      total_length <= N2,
+      "Combined length {} must be less than or equal to {}!",
+      total_length,
+      N2
+    );
+    let mut res = StaticVec::new();
+    for (i, item) in self.iter().enumerate() {
+      if i > 0 {
+        res.extend_from_slice(separator);
+      }
+      res.extend_from_slice(item.as_ref());
+    }
+    res
+  }
+
+  /// Copies all elements in `range` (which must be within `0..length`) and appends the copies to
+  /// the end of the StaticVec.
+  ///
+  /// Panics if the range is out of bounds, or if the StaticVec's length after appending the
+  /// copies would be greater than its capacity.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn extend_from_within<R>(&mut self, range: R)
+  where
+    R: RangeBounds<usize>,
+    T: Copy, {
+    let (start, end) = range_to_indices(range, self.length);
+    assert!(start <= end && end <= self.length);
+    let added_length = end - start;
+    assert!(
+      added_length <= self.remaining_capacity(),
+      "Insufficient remaining capacity!"
+    );
+    // Safety: the source range lies entirely within `0..length` and the destination starts at
+    // `length`, so the two regions can never overlap.
+    unsafe {
+      let p = self.as_mut_ptr();
+      p.add(start)
+        .copy_to_nonoverlapping(p.add(self.length), added_length);
+    }
+    self.length += added_length;
+  }
+
+  /// Overwrites the first `src.len()` elements of the StaticVec with copies of the elements of
+  /// `src`, leaving any remaining elements untouched. Unlike
+  /// [`extend_from_slice`](crate::StaticVec::extend_from_slice), this never changes the length of
+  /// the StaticVec.
+  ///
+  /// Panics if the length of `src` is greater than the length of the StaticVec.
+  ///
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn copy_from_slice(&mut self, src: &[T])
+  where T: Copy {
+    assert!(
+      src.len() <= self.length,
+      "Provided slice length {} must be less than or equal to {}!",
+      src.len(),
+      self.length
+    );
+    unsafe {
+      src
+        .as_ptr()
+        .copy_to_nonoverlapping(self.as_mut_ptr(), src.len());
+    }
+  }
+
+  /// Overwrites the first `src.len()` elements of the StaticVec with clones of the elements of
+  /// `src`, leaving any remaining elements untouched. Each overwritten element is dropped as it
+  /// is replaced. Unlike [`extend_from_slice`](crate::StaticVec::extend_from_slice), this never
+  /// changes the length of the StaticVec.
+  ///
+  /// Panics if the length of `src` is greater than the length of the StaticVec.
+  ///
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to make the cloning
+  /// possible.
+  #[inline]
+  pub fn clone_from_slice(&mut self, src: &[T])
+  where T: Clone {
+    assert!(
+      src.len() <= self.length,
+      "Provided slice length {} must be less than or equal to {}!",
+      src.len(),
+      self.length
+    );
+    for (dest, item) in self.iter_mut().zip(src) {
+      dest.clone_from(item);
+    }
+  }
+
+  /// Swaps every element of the StaticVec's inhabited area with the element at the same position
+  /// in `other`. Neither the StaticVec's length nor the length of `other` changes.
+  ///
+  /// Panics if the length of `other` is not equal to the length of the StaticVec.
+  #[inline]
+  pub fn swap_with_slice(&mut self, other: &mut [T]) {
+    assert!(
+      other.len() == self.length,
+      "Provided slice length {} must be equal to {}!",
+      other.len(),
+      self.length
+    );
+    // Safety: `other` can't overlap with the StaticVec's contents, since that would require a
+    // second mutable borrow of them.
+    unsafe { ptr::swap_nonoverlapping(self.as_mut_ptr(), other.as_mut_ptr(), self.length) };
+  }
+
   /// Appends `self.remaining_capacity()` (or as many as available) items from
   /// `other` to `self`. The appended items (if any) will no longer exist in `other` afterwards,
   /// as `other`'s `length` field will be adjusted to indicate.
@@ -739,6 +2177,104 @@ impl<T, const N: usize> StaticVec<T, N> {
     self.length += item_count;
   }
 
+  /// Appends all of the items from `other` to `self` exactly as
+  /// [`append`](crate::StaticVec::append) does if `self` has enough remaining capacity for all of
+  /// them, leaving `other` empty, or returns an error indicating that's not the case otherwise,
+  /// in which case both StaticVecs are left completely unmodified.
+  #[inline]
+  pub fn try_append<const N2: usize>(
+    &mut self,
+    other: &mut StaticVec<T, N2>,
+  ) -> Result<(), &'static str>
+  {
+    if other.length <= self.remaining_capacity() {
+      self.append(other);
+      Ok(())
+    } else {
+      Err("Insufficient remaining capacity!")
+    }
+  }
+
+  /// Appends items from `iter` to the StaticVec until either `iter` is exhausted or the StaticVec
+  /// is full, and returns the number of items that were appended. Once the StaticVec is full no
+  /// further items are pulled from `iter` at all, so comparing the returned count against the
+  /// number of items that were available makes it easy to detect backpressure.
+  #[inline]
+  pub fn append_from_iter<I>(&mut self, iter: I) -> usize
+  where I: IntoIterator<Item = T> {
+    let old_length = self.length;
+    let mut it = iter.into_iter();
+    while self.length < N {
+      match it.next() {
+        Some(item) => unsafe { self.push_unchecked(item) },
+        None => break,
+      }
+    }
+    self.length - old_length
+  }
+
+  /// Consumes the StaticVec, moving all of its elements into a new StaticVec with a capacity of
+  /// `N2`, which may be either larger or smaller than `N`. The elements are moved rather than
+  /// copied, so `T` does not need to implement [`Copy`](core::marker::Copy) or
+  /// [`Clone`](core::clone::Clone).
+  ///
+  /// Panics if the length of the StaticVec is greater than `N2`.
+  #[inline]
+  pub fn into_capacity<const N2: usize>(self) -> StaticVec<T, N2> {
+    assert!(
+      self.length <= N2,
+      "Length {} must be less than or equal to {}!",
+      self.length,
+      N2
+    );
+    let mut res = StaticVec::new();
+    unsafe {
+      self
+        .as_ptr()
+        .copy_to_nonoverlapping(res.as_mut_ptr(), self.length);
+      res.set_len(self.length);
+    }
+    // The elements now belong to `res`, so we must not run `self`'s destructor.
+    mem::forget(self);
+    res
+  }
+
+  /// Returns a copy of the StaticVec's inhabited area in a new StaticVec with a capacity of `N2`,
+  /// which may be either larger or smaller than `N`, without consuming the original. This is the
+  /// non-consuming counterpart to [`into_capacity`](crate::StaticVec::into_capacity).
+  ///
+  /// Panics if the length of the StaticVec is greater than `N2`.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn to_capacity<const N2: usize>(&self) -> StaticVec<T, N2>
+  where T: Copy {
+    assert!(
+      self.length <= N2,
+      "Length {} must be less than or equal to {}!",
+      self.length,
+      N2
+    );
+    StaticVec::new_from_slice(self)
+  }
+
+  /// Returns a new StaticVec with a capacity of `N2`, which may be either larger or smaller than
+  /// `N`, containing clones of the contents of the StaticVec's inhabited area. This is the
+  /// [`Clone`](core::clone::Clone) counterpart to [`to_capacity`](crate::StaticVec::to_capacity).
+  /// If a call to `clone` panics, all of the elements cloned before that point are dropped.
+  ///
+  /// Panics if the length of the StaticVec is greater than `N2`.
+  #[inline]
+  pub fn clone_into<const N2: usize>(&self) -> StaticVec<T, N2>
+  where T: Clone {
+    assert!(
+      self.length <= N2,
+      "Length {} must be less than or equal to {}!",
+      self.length,
+      N2
+    );
+    StaticVec::clone_from_slice_new(self)
+  }
+
   /// Returns a [`Vec`](alloc::vec::Vec) containing the contents of the StaticVec instance.
   /// The returned [`Vec`](alloc::vec::Vec) will initially have the same value for
   /// [`len`](alloc::vec::Vec::len) and [`capacity`](alloc::vec::Vec::capacity) as the source
@@ -760,73 +2296,176 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
-  /// Removes the specified range of elements from the StaticVec and returns them in a new one.
+  /// Removes the specified range of elements from the StaticVec and returns a
+  /// [`StaticVecDrain`](crate::iterators::StaticVecDrain) that yields them by value.
+  /// The elements are removed lazily: when the returned iterator is dropped, any elements in the
+  /// range that were not yielded are dropped, and the remaining elements after the range are
+  /// shifted to the left to close the gap. Panics if the range is out of bounds.
+  ///
+  /// If the returned iterator is leaked (for example, with [`mem::forget`](core::mem::forget)),
+  /// the StaticVec will be left containing only the elements that came before the range.
   #[inline]
-  pub fn drain<R>(&mut self, range: R) -> Self
+  pub fn drain<R>(&mut self, range: R) -> StaticVecDrain<T, N>
   // No Copy bounds here because the original StaticVec gives up all access to the values in
   // question.
   where R: RangeBounds<usize> {
-    // Borrowed this part from normal Vec's implementation.
-    let start = match range.start_bound() {
-      Included(&idx) => idx,
-      Excluded(&idx) => idx + 1,
-      Unbounded => 0,
-    };
-    let end = match range.end_bound() {
-      Included(&idx) => idx + 1,
-      Excluded(&idx) => idx,
-      Unbounded => self.length,
-    };
+    let (start, end) = range_to_indices(range, self.length);
     assert!(start <= end && end <= self.length);
-    let res_length = end - start;
-    Self {
-      data: {
-        let mut res = Self::new_data_uninit();
-        unsafe {
-          self
-            .ptr_at_unchecked(start)
-            .copy_to_nonoverlapping(res.as_mut_ptr() as *mut T, res_length);
-          self
-            .ptr_at_unchecked(end)
-            .copy_to(self.mut_ptr_at_unchecked(start), self.length - end);
-          self.length -= res_length;
-          res.assume_init()
-        }
-      },
-      length: res_length,
+    let old_length = self.length;
+    unsafe {
+      // Truncating the length up front is what makes leaking the returned iterator safe.
+      self.set_len(start);
+      StaticVecDrain {
+        start: end,
+        length: old_length - end,
+        iter: StaticVecIterConst {
+          start: match intrinsics::size_of::<T>() {
+            0 => (self.as_ptr() as *const u8).wrapping_add(start) as *const T,
+            _ => self.ptr_at_unchecked(start),
+          },
+          end: match intrinsics::size_of::<T>() {
+            0 => (self.as_ptr() as *const u8).wrapping_add(end) as *const T,
+            _ => self.ptr_at_unchecked(end),
+          },
+          marker: PhantomData,
+        },
+        vec: self,
+      }
+    }
+  }
+
+  /// Removes the specified range of elements from the StaticVec, dropping them in place, and
+  /// shifts the remaining elements after the range to the left to close the gap. This is
+  /// equivalent to calling [`drain`](crate::StaticVec::drain) and immediately dropping the
+  /// returned iterator, without the overhead of yielding anything. Panics if the range is out of
+  /// bounds.
+  #[inline]
+  pub fn remove_range<R>(&mut self, range: R)
+  where R: RangeBounds<usize> {
+    let (start, end) = range_to_indices(range, self.length);
+    assert!(start <= end && end <= self.length);
+    let old_length = self.length;
+    unsafe {
+      // As with `drain`, truncating the length first means that a panicking destructor can only
+      // leak the tail rather than cause it to be dropped twice.
+      self.set_len(start);
+      let p = self.as_mut_ptr();
+      ptr::drop_in_place(slice::from_raw_parts_mut(p.add(start), end - start));
+      p.add(end).copy_to(p.add(start), old_length - end);
+      self.set_len(old_length - (end - start));
+    }
+  }
+
+  /// Removes the specified range of elements from the StaticVec, replaces them with the
+  /// contents of `replace_with`, and returns the removed elements in a new StaticVec.
+  /// Any elements that came after the range are shifted left or right as necessary.
+  /// `replace_with` does not need to yield the same number of items as the length of the range.
+  ///
+  /// Panics if the range is out of bounds, or if the StaticVec's length after the
+  /// replacement would be greater than its capacity. In either case, the StaticVec is left
+  /// unmodified.
+  #[inline]
+  pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Self
+  where
+    R: RangeBounds<usize>,
+    I: IntoIterator<Item = T>, {
+    let (start, end) = range_to_indices(range, self.length);
+    assert!(start <= end && end <= self.length);
+    let removed_length = end - start;
+    let tail_length = self.length - end;
+    // The replacement items are collected up front so that nothing in `self` is touched until
+    // the new length is known to fit.
+    let mut it = replace_with.into_iter();
+    let mut replacement: Self = it.by_ref().collect();
+    let inserted_length = replacement.length;
+    assert!(
+      it.next().is_none() && self.length - removed_length + inserted_length <= N,
+      "Insufficient remaining capacity!"
+    );
+    let mut res = Self::new();
+    unsafe {
+      self
+        .ptr_at_unchecked(start)
+        .copy_to_nonoverlapping(res.as_mut_ptr(), removed_length);
+      res.set_len(removed_length);
+      self.ptr_at_unchecked(end).copy_to(
+        self.mut_ptr_at_unchecked(start + inserted_length),
+        tail_length,
+      );
+      replacement
+        .as_ptr()
+        .copy_to_nonoverlapping(self.mut_ptr_at_unchecked(start), inserted_length);
+      replacement.set_len(0);
+      self.set_len(start + inserted_length + tail_length);
     }
+    res
   }
 
-  /// Removes all elements in the StaticVec for which `filter` returns true and
-  /// returns them in a new one.
+  /// Returns a [`StaticVecDrainFilter`](crate::iterators::StaticVecDrainFilter) that removes
+  /// and yields, by value, each element of the StaticVec for which `filter` returns true. The
+  /// elements that are kept are shifted to the left as the iterator advances, keeping their
+  /// original order.
+  ///
+  /// `filter` is called lazily, as the iterator is advanced. If the iterator is dropped before it
+  /// has been fully consumed, `filter` is called on each of the remaining elements and any for
+  /// which it returns true are dropped, so the final state of the StaticVec is always the same
+  /// regardless of how much of the iterator was consumed.
+  ///
+  /// If the returned iterator is leaked (for example, with [`mem::forget`](core::mem::forget)),
+  /// the StaticVec will be left empty.
+  ///
+  /// Example usage:
+  /// ```
+  /// let mut numbers = staticvec![1, 2, 3, 4, 5, 6];
+  /// let evens: StaticVec<i32, 6> = numbers.drain_filter(|x| *x % 2 == 0).collect();
+  /// assert_eq!(evens, [2, 4, 6]);
+  /// assert_eq!(numbers, [1, 3, 5]);
+  /// ```
   #[inline]
-  pub fn drain_filter<F>(&mut self, mut filter: F) -> Self
+  pub fn drain_filter<F>(&mut self, filter: F) -> StaticVecDrainFilter<T, F, N>
   where F: FnMut(&mut T) -> bool {
-    let mut res = Self::new();
     let old_length = self.length;
+    // Setting the length to 0 up front is what makes leaking the returned iterator safe.
     self.length = 0;
-    unsafe {
-      for i in 0..old_length {
-        let val = self.mut_ptr_at_unchecked(i);
-        if filter(&mut *val) {
-          res.data.get_unchecked_mut(res.length).write(val.read());
-          res.length += 1;
-        } else if res.length > 0 {
-          self
-            .ptr_at_unchecked(i)
-            .copy_to_nonoverlapping(self.mut_ptr_at_unchecked(i - res.length), 1);
-        }
-      }
+    StaticVecDrainFilter {
+      vec: self,
+      index: 0,
+      deleted: 0,
+      old_length,
+      filter,
+      panic_flag: false,
     }
-    self.length = old_length - res.length;
-    res
   }
 
   /// Removes all elements in the StaticVec for which `filter` returns false.
   #[inline(always)]
   pub fn retain<F>(&mut self, mut filter: F)
   where F: FnMut(&T) -> bool {
-    self.drain_filter(|val| !filter(val));
+    self.drain_filter(|val| !filter(val)).for_each(drop);
+  }
+
+  /// Removes all elements in the StaticVec for which `filter` returns false. Unlike
+  /// [`retain`](crate::StaticVec::retain), `filter` receives a mutable reference to each element,
+  /// so it can also modify the elements that are kept.
+  #[inline(always)]
+  pub fn retain_mut<F>(&mut self, mut filter: F)
+  where F: FnMut(&mut T) -> bool {
+    self.drain_filter(|val| !filter(val)).for_each(drop);
+  }
+
+  /// Removes all elements in the StaticVec for which `filter` returns false. `filter` is also
+  /// passed the index each element had in the StaticVec before any of them were removed.
+  #[inline(always)]
+  pub fn retain_with_index<F>(&mut self, mut filter: F)
+  where F: FnMut(usize, &T) -> bool {
+    let mut index = 0;
+    self
+      .drain_filter(|val| {
+        let keep = filter(index, val);
+        index += 1;
+        !keep
+      })
+      .for_each(drop);
   }
 
   /// Shortens the StaticVec, keeping the first `length` elements and dropping the rest.
@@ -842,6 +2481,159 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Asserts that `index` is less than or equal to the current length of the StaticVec, and if so
+  /// drops every element from `index` onwards. This is a stricter version of
+  /// [`truncate`](crate::StaticVec::truncate), which silently does nothing when given a length
+  /// greater than the current one.
+  #[inline(always)]
+  pub fn clear_from(&mut self, index: usize) {
+    assert!(
+      index <= self.length,
+      "Provided index {} must be less than or equal to {}!",
+      index,
+      self.length
+    );
+    self.truncate(index);
+  }
+
+  /// Removes and drops the first `n` elements of the StaticVec, shifting the remaining elements
+  /// to the left. If `n` is greater than the current length of the StaticVec, it is clamped to it,
+  /// leaving the StaticVec empty.
+  #[inline]
+  pub fn drop_first(&mut self, n: usize) {
+    let old_length = self.length;
+    let n = n.min(old_length);
+    if n == 0 {
+      return;
+    }
+    // Set the length to zero first so that nothing is dropped twice (only leaked) if one of the
+    // destructors panics.
+    self.length = 0;
+    unsafe {
+      let p = self.as_mut_ptr();
+      ptr::drop_in_place(slice::from_raw_parts_mut(p, n));
+      p.add(n).copy_to(p, old_length - n);
+    }
+    self.length = old_length - n;
+  }
+
+  /// Keeps only the last `n` elements of the StaticVec, dropping all of the ones before them and
+  /// shifting the kept ones to the front. Does nothing if `n` is greater than or equal to the
+  /// current length of the StaticVec.
+  #[inline(always)]
+  pub fn keep_last(&mut self, n: usize) {
+    self.drop_first(self.length.saturating_sub(n));
+  }
+
+  /// Resizes the StaticVec in-place so that its length is equal to `new_len`.
+  /// If `new_len` is greater than the current length, the difference is filled with clones of
+  /// `value`. If `new_len` is less than the current length, the StaticVec is simply truncated.
+  /// Panics if `new_len` is greater than the StaticVec's capacity.
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to make the cloning
+  /// possible.
+  #[inline]
+  pub fn resize(&mut self, new_len: usize, value: T)
+  where T: Clone {
+    assert!(
+      new_len <= N,
+      "Provided length {} must be less than or equal to {}!",
+      new_len,
+      N
+    );
+    if new_len > self.length {
+      unsafe {
+        // Incrementing the length as we go ensures that nothing is leaked if `clone` panics.
+        while self.length < new_len - 1 {
+          self
+            .data
+            .get_unchecked_mut(self.length)
+            .write(value.clone());
+          self.length += 1;
+        }
+        // The last slot can take `value` directly rather than a clone of it.
+        self.data.get_unchecked_mut(self.length).write(value);
+        self.length += 1;
+      }
+    } else {
+      self.truncate(new_len);
+    }
+  }
+
+  /// Resizes the StaticVec in-place exactly as [`resize`](crate::StaticVec::resize) does if
+  /// `new_len` is less than or equal to the StaticVec's capacity, or returns an error indicating
+  /// that's not the case otherwise, in which case the StaticVec is left completely unmodified.
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to make the cloning
+  /// possible.
+  #[inline]
+  pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), &'static str>
+  where T: Clone {
+    if new_len <= N {
+      self.resize(new_len, value);
+      Ok(())
+    } else {
+      Err("Insufficient remaining capacity!")
+    }
+  }
+
+  /// Resizes the StaticVec in-place so that its length is equal to `new_len`.
+  /// If `new_len` is greater than the current length, the difference is filled with the return
+  /// values of repeated calls to `f`. If `new_len` is less than the current length, the StaticVec
+  /// is simply truncated. Panics if `new_len` is greater than the StaticVec's capacity.
+  #[inline]
+  pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+  where F: FnMut() -> T {
+    assert!(
+      new_len <= N,
+      "Provided length {} must be less than or equal to {}!",
+      new_len,
+      N
+    );
+    if new_len > self.length {
+      while self.length < new_len {
+        unsafe {
+          self.data.get_unchecked_mut(self.length).write(f());
+        }
+        self.length += 1;
+      }
+    } else {
+      self.truncate(new_len);
+    }
+  }
+
+  /// Resizes the StaticVec in-place so that its length is equal to `new_len`.
+  /// If `new_len` is greater than the current length, the difference is filled with
+  /// `T::default()`. If `new_len` is less than the current length, the StaticVec is simply
+  /// truncated. Panics if `new_len` is greater than the StaticVec's capacity.
+  /// Locally requires that `T` implements [`Default`](core::default::Default) to make this
+  /// possible.
+  #[inline(always)]
+  pub fn resize_default(&mut self, new_len: usize)
+  where T: Default {
+    self.resize_with(new_len, Default::default);
+  }
+
+  /// Fills all of the StaticVec's currently uninhabited slots with clones of `value`, bringing its
+  /// length up to its capacity. This is equivalent to calling
+  /// [`resize`](crate::StaticVec::resize) with `N`, and does nothing if the StaticVec is already
+  /// full.
+  /// Locally requires that `T` implements [`Clone`](core::clone::Clone) to make the cloning
+  /// possible.
+  #[inline(always)]
+  pub fn fill_remaining(&mut self, value: T)
+  where T: Clone {
+    self.resize(N, value);
+  }
+
+  /// Fills all of the StaticVec's currently uninhabited slots with the return values of repeated
+  /// calls to `f`, bringing its length up to its capacity. This is equivalent to calling
+  /// [`resize_with`](crate::StaticVec::resize_with) with `N`, and does nothing (without ever
+  /// calling `f`) if the StaticVec is already full.
+  #[inline(always)]
+  pub fn fill_remaining_with<F>(&mut self, f: F)
+  where F: FnMut() -> T {
+    self.resize_with(N, f);
+  }
+
   /// Splits the StaticVec into two at the given index.
   /// The original StaticVec will contain elements `0..at`,
   /// and the new one will contain elements `at..length`.
@@ -862,6 +2654,97 @@ impl<T, const N: usize> StaticVec<T, N> {
     }
   }
 
+  /// Splits the StaticVec into two at the given index, as the complement to
+  /// [`split_off`](crate::StaticVec::split_off). The new StaticVec will contain elements `0..at`,
+  /// and the original one will contain elements `at..length`, shifted down to start at index 0.
+  #[inline]
+  pub fn split_off_front(&mut self, at: usize) -> Self {
+    assert!(at <= self.length);
+    let remaining_length = self.length - at;
+    let mut res = Self::new();
+    unsafe {
+      let p = self.as_mut_ptr();
+      p.copy_to_nonoverlapping(res.as_mut_ptr(), at);
+      res.set_len(at);
+      p.add(at).copy_to(p, remaining_length);
+    }
+    self.length = remaining_length;
+    res
+  }
+
+  /// Divides the StaticVec's inhabited area into two constant slices at the given index, without
+  /// modifying the StaticVec itself. The first slice will contain elements `0..mid`, and the
+  /// second will contain elements `mid..length`.
+  ///
+  /// Panics if `mid` is greater than the length of the StaticVec.
+  #[inline(always)]
+  pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+    assert!(
+      mid <= self.length,
+      "Provided index {} must be between 0 and {}!",
+      mid,
+      self.length
+    );
+    unsafe {
+      (
+        self.as_slice().get_unchecked(..mid),
+        self.as_slice().get_unchecked(mid..),
+      )
+    }
+  }
+
+  /// Divides the StaticVec's inhabited area into two disjoint mutable slices at the given index,
+  /// without modifying the StaticVec itself. The first slice will contain elements `0..mid`, and
+  /// the second will contain elements `mid..length`.
+  ///
+  /// Panics if `mid` is greater than the length of the StaticVec.
+  #[inline(always)]
+  pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+    assert!(
+      mid <= self.length,
+      "Provided index {} must be between 0 and {}!",
+      mid,
+      self.length
+    );
+    let length = self.length;
+    let p = self.as_mut_ptr();
+    // Safety: `mid <= length`, so the two slices are disjoint and both lie within `0..length`.
+    unsafe {
+      (
+        slice::from_raw_parts_mut(p, mid),
+        slice::from_raw_parts_mut(p.add(mid), length - mid),
+      )
+    }
+  }
+
+  /// Returns a constant reference to the first element of the StaticVec along with a slice of all
+  /// of the remaining elements in `Some` if the StaticVec is not empty, or `None` otherwise.
+  #[inline(always)]
+  pub fn split_first(&self) -> Option<(&T, &[T])> {
+    self.as_slice().split_first()
+  }
+
+  /// Returns a mutable reference to the first element of the StaticVec along with a mutable slice
+  /// of all of the remaining elements in `Some` if the StaticVec is not empty, or `None` otherwise.
+  #[inline(always)]
+  pub fn split_first_mut(&mut self) -> Option<(&mut T, &mut [T])> {
+    self.as_mut_slice().split_first_mut()
+  }
+
+  /// Returns a constant reference to the last element of the StaticVec along with a slice of all
+  /// of the preceding elements in `Some` if the StaticVec is not empty, or `None` otherwise.
+  #[inline(always)]
+  pub fn split_last(&self) -> Option<(&T, &[T])> {
+    self.as_slice().split_last()
+  }
+
+  /// Returns a mutable reference to the last element of the StaticVec along with a mutable slice
+  /// of all of the preceding elements in `Some` if the StaticVec is not empty, or `None` otherwise.
+  #[inline(always)]
+  pub fn split_last_mut(&mut self) -> Option<(&mut T, &mut [T])> {
+    self.as_mut_slice().split_last_mut()
+  }
+
   /// Removes all but the first of consecutive elements in the StaticVec satisfying a given equality
   /// relation.
   #[inline(always)]
@@ -892,6 +2775,224 @@ impl<T, const N: usize> StaticVec<T, N> {
     self.dedup_by(|a, b| key(a) == key(b))
   }
 
+  /// Removes all but the first occurrence of each distinct element in the StaticVec, whether or
+  /// not the duplicates are consecutive, according to the locally required
+  /// [`PartialEq`](core::cmp::PartialEq) trait implementation for `T`. Unlike sorting and then
+  /// calling [`dedup`](crate::StaticVec::dedup), this preserves the original order of the
+  /// elements that are kept. Each element is compared against all of the kept elements before
+  /// it, so this takes quadratic time.
+  #[inline]
+  pub fn dedup_unsorted(&mut self)
+  where T: PartialEq {
+    let slice = self.as_mut_slice();
+    let mut kept = 0;
+    for i in 0..slice.len() {
+      if !slice[..kept].contains(&slice[i]) {
+        // Everything between `kept` and `i` is a duplicate, so swapping moves the new element
+        // down without disturbing the order of the ones already kept.
+        slice.swap(kept, i);
+        kept += 1;
+      }
+    }
+    self.truncate(kept);
+  }
+
+  /// Removes consecutive repeated elements in the StaticVec according to the
+  /// locally required [`PartialEq`](core::cmp::PartialEq) trait implementation for `T`, in the
+  /// same way as [`dedup`](crate::StaticVec::dedup), but rather than dropping the removed
+  /// duplicates returns them in a new StaticVec with a capacity of `N2`, in the order they
+  /// originally appeared.
+  ///
+  /// Panics if the number of duplicates is greater than `N2`, in which case the StaticVec is
+  /// left unmodified.
+  #[inline]
+  pub fn dedup_and_collect<const N2: usize>(&mut self) -> StaticVec<T, N2>
+  where T: PartialEq {
+    let duplicate_count = self.windows(2).filter(|pair| pair[0] == pair[1]).count();
+    assert!(
+      duplicate_count <= N2,
+      "Number of duplicates {} must be less than or equal to {}!",
+      duplicate_count,
+      N2
+    );
+    let mut res = StaticVec::new();
+    let old_length = self.length;
+    if old_length < 2 {
+      return res;
+    }
+    // Setting the length to 0 up front means that a panic in `eq` can only leak the elements,
+    // rather than allow them to be dropped twice.
+    self.length = 0;
+    let mut write = 1;
+    unsafe {
+      let ptr = self.as_mut_ptr();
+      for read in 1..old_length {
+        if *ptr.add(read) == *ptr.add(write - 1) {
+          res.push(ptr.add(read).read());
+        } else {
+          if read != write {
+            ptr.add(read).copy_to_nonoverlapping(ptr.add(write), 1);
+          }
+          write += 1;
+        }
+      }
+      self.set_len(write);
+    }
+    res
+  }
+
+  /// Removes consecutive repeated elements in the StaticVec in the same way as
+  /// [`dedup`](crate::StaticVec::dedup), and returns a new StaticVec with a capacity of `N2`
+  /// containing a `(value, run_length)` pair for each run of equal elements that was reduced,
+  /// in order. This is effectively run-length encoding.
+  ///
+  /// Panics if the number of runs is greater than `N2`, in which case the StaticVec is left
+  /// unmodified.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues,
+  /// and [`PartialEq`](core::cmp::PartialEq) to make the comparison possible.
+  #[inline]
+  pub fn dedup_with_count<const N2: usize>(&mut self) -> StaticVec<(T, usize), N2>
+  where T: Copy + PartialEq {
+    let run_count = self.group_by(|a, b| a == b).count();
+    assert!(
+      run_count <= N2,
+      "Number of runs {} must be less than or equal to {}!",
+      run_count,
+      N2
+    );
+    let mut res = StaticVec::new();
+    for run in self.group_by(|a, b| a == b) {
+      unsafe { res.push_unchecked((run[0], run.len())) };
+    }
+    for (i, &(value, _)) in res.iter().enumerate() {
+      unsafe { self.mut_ptr_at_unchecked(i).write(value) };
+    }
+    self.length = run_count;
+    res
+  }
+
+  /// Returns a new StaticVec with a capacity of `N3` containing every element that is present in
+  /// both the StaticVec and `other`, in sorted order. This is computed with a single linear merge
+  /// pass, and so requires that both inputs are already sorted and free of duplicates; if they
+  /// are not, the contents of the result are unspecified (though still memory-safe).
+  ///
+  /// Panics if the result does not fit in `N3`.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn intersection<const N2: usize, const N3: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, N3>
+  where
+    T: Ord + Copy,
+  {
+    let mut res = StaticVec::new();
+    let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+    while let (Some(&&x), Some(&&y)) = (a.peek(), b.peek()) {
+      match x.cmp(&y) {
+        Ordering::Less => {
+          a.next();
+        }
+        Ordering::Greater => {
+          b.next();
+        }
+        Ordering::Equal => {
+          res.push(x);
+          a.next();
+          b.next();
+        }
+      }
+    }
+    res
+  }
+
+  /// Returns a new StaticVec with a capacity of `N3` containing every element that is present in
+  /// either the StaticVec or `other` (or both), in sorted order. This has the same sortedness
+  /// requirements for its inputs as [`intersection`](crate::StaticVec::intersection).
+  ///
+  /// Panics if the result does not fit in `N3`.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn union<const N2: usize, const N3: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, N3>
+  where
+    T: Ord + Copy,
+  {
+    let mut res = StaticVec::new();
+    let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+    loop {
+      match (a.peek(), b.peek()) {
+        (Some(&&x), Some(&&y)) => match x.cmp(&y) {
+          Ordering::Less => {
+            res.push(x);
+            a.next();
+          }
+          Ordering::Greater => {
+            res.push(y);
+            b.next();
+          }
+          Ordering::Equal => {
+            res.push(x);
+            a.next();
+            b.next();
+          }
+        },
+        (Some(&&x), None) => {
+          res.push(x);
+          a.next();
+        }
+        (None, Some(&&y)) => {
+          res.push(y);
+          b.next();
+        }
+        (None, None) => break,
+      }
+    }
+    res
+  }
+
+  /// Returns a new StaticVec with a capacity of `N3` containing every element that is present in
+  /// the StaticVec but not in `other`, in sorted order. This has the same sortedness requirements
+  /// for its inputs as [`intersection`](crate::StaticVec::intersection).
+  ///
+  /// Panics if the result does not fit in `N3`.
+  /// Locally requires that `T` implements [`Copy`](core::marker::Copy) to avoid soundness issues.
+  #[inline]
+  pub fn difference<const N2: usize, const N3: usize>(
+    &self,
+    other: &StaticVec<T, N2>,
+  ) -> StaticVec<T, N3>
+  where
+    T: Ord + Copy,
+  {
+    let mut res = StaticVec::new();
+    let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+    while let Some(&&x) = a.peek() {
+      match b.peek() {
+        Some(&&y) => match x.cmp(&y) {
+          Ordering::Less => {
+            res.push(x);
+            a.next();
+          }
+          Ordering::Greater => {
+            b.next();
+          }
+          Ordering::Equal => {
+            a.next();
+            b.next();
+          }
+        },
+        None => {
+          res.push(x);
+          a.next();
+        }
+      }
+    }
+    res
+  }
+
   #[doc(hidden)]
   #[inline(always)]
   pub(crate) fn new_data() -> [MaybeUninit<T>; N] {
@@ -907,3 +3008,236 @@ impl<T, const N: usize> StaticVec<T, N> {
     MaybeUninit::uninit()
   }
 }
+
+impl<const N: usize> StaticVec<u8, N> {
+  impl_push_int!(
+    /// Appends the little-endian byte representation of the unsigned 16-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 2 bytes otherwise (in which case nothing is appended).
+    push_u16_le,
+    u16,
+    to_le_bytes
+  );
+  impl_push_int!(
+    /// Appends the big-endian byte representation of the unsigned 16-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 2 bytes otherwise (in which case nothing is appended).
+    push_u16_be,
+    u16,
+    to_be_bytes
+  );
+  impl_push_int!(
+    /// Appends the little-endian byte representation of the signed 16-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 2 bytes otherwise (in which case nothing is appended).
+    push_i16_le,
+    i16,
+    to_le_bytes
+  );
+  impl_push_int!(
+    /// Appends the big-endian byte representation of the signed 16-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 2 bytes otherwise (in which case nothing is appended).
+    push_i16_be,
+    i16,
+    to_be_bytes
+  );
+  impl_push_int!(
+    /// Appends the little-endian byte representation of the unsigned 32-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 4 bytes otherwise (in which case nothing is appended).
+    push_u32_le,
+    u32,
+    to_le_bytes
+  );
+  impl_push_int!(
+    /// Appends the big-endian byte representation of the unsigned 32-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 4 bytes otherwise (in which case nothing is appended).
+    push_u32_be,
+    u32,
+    to_be_bytes
+  );
+  impl_push_int!(
+    /// Appends the little-endian byte representation of the signed 32-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 4 bytes otherwise (in which case nothing is appended).
+    push_i32_le,
+    i32,
+    to_le_bytes
+  );
+  impl_push_int!(
+    /// Appends the big-endian byte representation of the signed 32-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 4 bytes otherwise (in which case nothing is appended).
+    push_i32_be,
+    i32,
+    to_be_bytes
+  );
+  impl_push_int!(
+    /// Appends the little-endian byte representation of the unsigned 64-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 8 bytes otherwise (in which case nothing is appended).
+    push_u64_le,
+    u64,
+    to_le_bytes
+  );
+  impl_push_int!(
+    /// Appends the big-endian byte representation of the unsigned 64-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 8 bytes otherwise (in which case nothing is appended).
+    push_u64_be,
+    u64,
+    to_be_bytes
+  );
+  impl_push_int!(
+    /// Appends the little-endian byte representation of the signed 64-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 8 bytes otherwise (in which case nothing is appended).
+    push_i64_le,
+    i64,
+    to_le_bytes
+  );
+  impl_push_int!(
+    /// Appends the big-endian byte representation of the signed 64-bit integer `value`
+    /// to the StaticVec, or returns an error indicating there's insufficient remaining capacity
+    /// for all 8 bytes otherwise (in which case nothing is appended).
+    push_i64_be,
+    i64,
+    to_be_bytes
+  );
+
+  /// Returns a new StaticVec with a capacity of `M` containing the lowercase hexadecimal ASCII
+  /// representation of the StaticVec's contents, with two digits per byte. No allocation is
+  /// performed at any point.
+  ///
+  /// Panics if `M` is less than twice the length of the StaticVec.
+  #[inline(always)]
+  pub fn to_hex<const M: usize>(&self) -> StaticVec<u8, M> {
+    self.to_hex_with_digits(b"0123456789abcdef")
+  }
+
+  /// Returns a new StaticVec with a capacity of `M` containing the uppercase hexadecimal ASCII
+  /// representation of the StaticVec's contents, in the same way as
+  /// [`to_hex`](crate::StaticVec::to_hex).
+  ///
+  /// Panics if `M` is less than twice the length of the StaticVec.
+  #[inline(always)]
+  pub fn to_hex_upper<const M: usize>(&self) -> StaticVec<u8, M> {
+    self.to_hex_with_digits(b"0123456789ABCDEF")
+  }
+
+  /// Returns a [`StaticVecReader`](crate::StaticVecReader) positioned at the start of the
+  /// StaticVec's inhabited area, for sequentially reading bytes and integers out of it.
+  #[inline(always)]
+  pub fn reader(&self) -> StaticVecReader {
+    StaticVecReader {
+      data: self.as_slice(),
+      position: 0,
+    }
+  }
+
+  /// Returns the index of the first occurrence of `needle` in the StaticVec's inhabited area, or
+  /// `None` if it is not present. The search is performed a machine word at a time rather than
+  /// byte-by-byte, which is considerably faster for long buffers.
+  #[inline(always)]
+  pub fn find_byte(&self, needle: u8) -> Option<usize> {
+    find_byte(self, needle)
+  }
+
+  /// Returns the index of the last occurrence of `needle` in the StaticVec's inhabited area, or
+  /// `None` if it is not present. This uses the same word-at-a-time technique as
+  /// [`find_byte`](crate::StaticVec::find_byte), but scans from the end.
+  #[inline(always)]
+  pub fn rfind_byte(&self, needle: u8) -> Option<usize> {
+    rfind_byte(self, needle)
+  }
+
+  /// Overwrites the entire backing array of the StaticVec (not just its inhabited area) with
+  /// zeros and sets its length to 0. The writes are volatile and followed by a compiler fence, so
+  /// they will not be optimized away even if the StaticVec is never read from again, making this
+  /// suitable for clearing sensitive data such as key material.
+  #[inline]
+  pub fn zeroize(&mut self) {
+    self.length = 0;
+    let p = self.as_mut_ptr();
+    for i in 0..N {
+      unsafe { ptr::write_volatile(p.add(i), 0) };
+    }
+    atomic::compiler_fence(atomic::Ordering::SeqCst);
+  }
+
+  #[inline]
+  fn to_hex_with_digits<const M: usize>(&self, digits: &[u8; 16]) -> StaticVec<u8, M> {
+    assert!(
+      self.length * 2 <= M,
+      "Encoded length {} must be less than or equal to {}!",
+      self.length * 2,
+      M
+    );
+    let mut res = StaticVec::new();
+    for &byte in self.iter() {
+      unsafe {
+        res.push_unchecked(digits[(byte >> 4) as usize]);
+        res.push_unchecked(digits[(byte & 0xF) as usize]);
+      }
+    }
+    res
+  }
+}
+
+impl<const N: usize> StaticVec<bool, N> {
+  /// Packs the contents of the StaticVec's inhabited area into a new `StaticVec<u8, M>`, eight
+  /// booleans to a byte. Bits are assigned LSB-first: element `i` of the StaticVec is stored in
+  /// bit `i % 8` of byte `i / 8`. Any unused high bits in the final byte are zero. The returned
+  /// StaticVec has a length of exactly `(self.len() + 7) / 8`.
+  ///
+  /// Panics if `M` is too small to hold all of the packed bits. Choosing `M` as `(N + 7) / 8`
+  /// always suffices.
+  #[inline]
+  pub fn to_packed_bits<const M: usize>(&self) -> StaticVec<u8, M> {
+    let byte_count = (self.length + 7) / 8;
+    assert!(
+      byte_count <= M,
+      "Packed length {} must be less than or equal to {}!",
+      byte_count,
+      M
+    );
+    let mut res = StaticVec::new();
+    for chunk in self.chunks(8) {
+      let byte = chunk
+        .iter()
+        .enumerate()
+        .fold(0u8, |acc, (i, &bit)| acc | ((bit as u8) << i));
+      unsafe { res.push_unchecked(byte) };
+    }
+    res
+  }
+
+  /// Returns a new StaticVec containing the first `bit_count` booleans unpacked from `bytes`,
+  /// using the same LSB-first bit order as [`to_packed_bits`](crate::StaticVec::to_packed_bits),
+  /// which this is the inverse of.
+  ///
+  /// Panics if `bit_count` is greater than the StaticVec's capacity, or if `bytes` contains fewer
+  /// than `bit_count` bits.
+  #[inline]
+  pub fn from_packed_bits(bytes: &[u8], bit_count: usize) -> Self {
+    assert!(
+      bit_count <= N,
+      "Bit count {} must be less than or equal to {}!",
+      bit_count,
+      N
+    );
+    assert!(
+      bit_count <= bytes.len() * 8,
+      "Bit count {} must be less than or equal to {}!",
+      bit_count,
+      bytes.len() * 8
+    );
+    let mut res = Self::new();
+    for i in 0..bit_count {
+      unsafe { res.push_unchecked(bytes.get_unchecked(i / 8) & (1 << (i % 8)) != 0) };
+    }
+    res
+  }
+}
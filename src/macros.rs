@@ -1,7 +1,9 @@
 /// Creates a new StaticVec from a [`vec!`](https://doc.rust-lang.org/nightly/alloc/macro.vec.html)-style pseudo-slice.
 /// The newly created StaticVec will have a capacity and length exactly equal to the
 /// number of elements in the slice. The "array-like" `[value; N]` syntax is also supported for
-/// types that implement [`Copy`](core::marker::Copy).
+/// types that implement [`Clone`](core::clone::Clone), in which case the value is cloned `N` times
+/// using [`filled_with`](crate::StaticVec::filled_with). An empty `staticvec![]` produces a
+/// StaticVec with a length of 0 and a capacity inferred from context.
 ///
 /// Example usage:
 ///
@@ -10,6 +12,10 @@
 /// let v = staticvec![vec![staticvec![1, 2, 3, 4]]];
 /// // The type of the StaticVec on the next line is `StaticVec<f32, 64>`.
 /// let v2 = staticvec![12.0; 64];
+/// // The type of the StaticVec on the next line is `StaticVec<String, 4>`.
+/// let v3 = staticvec![String::from("A"); 4];
+/// // The type of the StaticVec on the next line is `StaticVec<i32, 8>`.
+/// let v4: StaticVec<i32, 8> = staticvec![];
 /// ```
 #[macro_export]
 macro_rules! staticvec {
@@ -27,7 +33,9 @@ macro_rules! staticvec {
 macro_rules! impl_extend {
   ($var_a:tt, $var_b:tt, $type:ty) => {
     /// Appends all elements, if any, from `iter` to the StaticVec. If `iter` has a size greater than
-    /// the StaticVec's capacity, any items after that point are ignored.
+    /// the StaticVec's remaining capacity, any items after that point are ignored (and are never
+    /// pulled from `iter` at all) rather than causing a panic, consistent with
+    /// [`extend_from_slice`](crate::StaticVec::extend_from_slice).
     #[inline]
     fn extend<I: IntoIterator<Item = $type>>(&mut self, iter: I) {
       let mut it = iter.into_iter();
@@ -51,7 +59,8 @@ macro_rules! impl_from_iterator {
   ($var_a:tt, $var_b:tt, $type:ty) => {
     /// Creates a new StaticVec instance from the elements, if any, of `iter`.
     /// If `iter` has a size greater than the StaticVec's capacity, any items after
-    /// that point are ignored.
+    /// that point are ignored (and are never pulled from `iter` at all), consistent with
+    /// [`new_from_array`](crate::StaticVec::new_from_array).
     #[inline]
     fn from_iter<I: IntoIterator<Item = $type>>(iter: I) -> Self {
       let mut i = 0;
@@ -173,3 +182,13 @@ macro_rules! impl_partial_ord_with_as_slice_against_slice {
     }
   };
 }
+
+macro_rules! impl_push_int {
+  ($(#[$attr:meta])* $name:ident, $type:ty, $to_bytes:ident) => {
+    $(#[$attr])*
+    #[inline(always)]
+    pub fn $name(&mut self, value: $type) -> Result<(), &'static str> {
+      self.try_extend_from_slice(&value.$to_bytes())
+    }
+  };
+}
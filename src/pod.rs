@@ -0,0 +1,24 @@
+/// A marker trait for "plain old data" types: types for which every possible sequence of
+/// `size_of::<Self>()` bytes is a valid value, and which contain no padding bytes. This is what
+/// makes it sound for [`as_bytes`](crate::StaticVec::as_bytes) and
+/// [`try_from_bytes`](crate::StaticVec::try_from_bytes) to reinterpret a StaticVec's contents as
+/// raw bytes and back.
+///
+/// It is implemented for all of the primitive integer and floating-point types, and for arrays
+/// of any type that implements it.
+///
+/// # Safety
+///
+/// Implementing this trait for a type that has padding bytes, invalid bit patterns (such as
+/// `bool`, `char`, references, or most enums), or interior pointers is undefined behavior.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+  ($($type:ty),*) => {
+    $(unsafe impl Pod for $type {})*
+  };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
@@ -0,0 +1,122 @@
+use core::fmt::{self, Debug, Formatter};
+
+/// A cursor for sequentially reading values out of the inhabited area of a `StaticVec<u8, N>`,
+/// which is the counterpart to methods such as [`push_u16_le`](crate::StaticVec::push_u16_le).
+/// Instances of [`StaticVecReader`](crate::StaticVecReader) are created by the
+/// [`reader`](crate::StaticVec::reader) method on [`StaticVec`](crate::StaticVec).
+///
+/// Every read either succeeds entirely and advances the cursor past the bytes it consumed, or
+/// returns `None` and leaves the cursor where it was.
+#[derive(Clone)]
+pub struct StaticVecReader<'a> {
+  pub(crate) data: &'a [u8],
+  pub(crate) position: usize,
+}
+
+macro_rules! impl_read_int {
+  ($(#[$attr:meta])* $name:ident, $type:ty, $from_bytes:ident) => {
+    $(#[$attr])*
+    #[inline]
+    pub fn $name(&mut self) -> Option<$type> {
+      let mut bytes = [0; core::mem::size_of::<$type>()];
+      bytes.copy_from_slice(self.read_slice(core::mem::size_of::<$type>())?);
+      Some(<$type>::$from_bytes(bytes))
+    }
+  };
+}
+
+impl<'a> StaticVecReader<'a> {
+  /// Returns the current read position, in bytes from the start of the StaticVec.
+  #[inline(always)]
+  pub const fn position(&self) -> usize {
+    self.position
+  }
+
+  /// Returns the number of bytes remaining to be read.
+  #[inline(always)]
+  pub fn remaining(&self) -> usize {
+    self.data.len() - self.position
+  }
+
+  /// Returns true if there are no bytes remaining to be read.
+  #[inline(always)]
+  pub fn is_empty(&self) -> bool {
+    self.remaining() == 0
+  }
+
+  /// Returns a slice of all of the bytes remaining to be read, without advancing the cursor.
+  #[inline(always)]
+  pub fn remaining_slice(&self) -> &'a [u8] {
+    unsafe { self.data.get_unchecked(self.position..) }
+  }
+
+  /// Returns the next `length` bytes as a slice in `Some` and advances the cursor past them, or
+  /// returns `None` if fewer than `length` bytes remain.
+  #[inline]
+  pub fn read_slice(&mut self, length: usize) -> Option<&'a [u8]> {
+    if length > self.remaining() {
+      return None;
+    }
+    let res = unsafe {
+      self
+        .data
+        .get_unchecked(self.position..self.position + length)
+    };
+    self.position += length;
+    Some(res)
+  }
+
+  /// Returns the next byte in `Some` and advances the cursor past it, or returns `None` if there
+  /// are no bytes remaining.
+  #[inline]
+  pub fn read_u8(&mut self) -> Option<u8> {
+    self.read_slice(1).map(|bytes| bytes[0])
+  }
+
+  impl_read_int!(
+    /// Reads a little-endian unsigned 16-bit integer.
+    read_u16_le,
+    u16,
+    from_le_bytes
+  );
+  impl_read_int!(
+    /// Reads a big-endian unsigned 16-bit integer.
+    read_u16_be,
+    u16,
+    from_be_bytes
+  );
+  impl_read_int!(
+    /// Reads a little-endian unsigned 32-bit integer.
+    read_u32_le,
+    u32,
+    from_le_bytes
+  );
+  impl_read_int!(
+    /// Reads a big-endian unsigned 32-bit integer.
+    read_u32_be,
+    u32,
+    from_be_bytes
+  );
+  impl_read_int!(
+    /// Reads a little-endian unsigned 64-bit integer.
+    read_u64_le,
+    u64,
+    from_le_bytes
+  );
+  impl_read_int!(
+    /// Reads a big-endian unsigned 64-bit integer.
+    read_u64_be,
+    u64,
+    from_be_bytes
+  );
+}
+
+impl<'a> Debug for StaticVecReader<'a> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_struct("StaticVecReader")
+      .field("position", &self.position)
+      .field("remaining", &self.remaining_slice())
+      .finish()
+  }
+}
@@ -0,0 +1,234 @@
+use crate::StaticVec;
+use core::fmt::{self, Debug, Formatter};
+use core::iter::Chain;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::slice;
+
+/// A fixed-capacity circular buffer, backed by an array in the same way as
+/// [`StaticVec`](crate::StaticVec). Unlike the shift-based
+/// [`push_front`](crate::StaticVec::push_front) and [`pop_front`](crate::StaticVec::pop_front)
+/// methods on StaticVec, pushing to the back and popping from the front of a
+/// [`StaticRingBuffer`](crate::StaticRingBuffer) are both O(1), as the elements never move once
+/// written. When the buffer is full, [`push_back`](crate::StaticRingBuffer::push_back) overwrites
+/// the oldest element.
+pub struct StaticRingBuffer<T, const N: usize> {
+  data: [MaybeUninit<T>; N],
+  // The physical index of the logically first (that is, oldest) element.
+  head: usize,
+  length: usize,
+}
+
+impl<T, const N: usize> StaticRingBuffer<T, N> {
+  /// Returns a new, empty StaticRingBuffer instance.
+  #[inline(always)]
+  pub fn new() -> Self {
+    Self {
+      data: StaticVec::<T, N>::new_data(),
+      head: 0,
+      length: 0,
+    }
+  }
+
+  /// Returns the current number of elements in the StaticRingBuffer.
+  #[inline(always)]
+  pub const fn len(&self) -> usize {
+    self.length
+  }
+
+  /// Returns the total capacity of the StaticRingBuffer, which is always equal to `N`.
+  #[inline(always)]
+  pub const fn capacity(&self) -> usize {
+    N
+  }
+
+  /// Returns true if the StaticRingBuffer contains no elements.
+  #[inline(always)]
+  pub const fn is_empty(&self) -> bool {
+    self.length == 0
+  }
+
+  /// Returns true if the StaticRingBuffer is at maximum capacity, in which case the next call to
+  /// [`push_back`](crate::StaticRingBuffer::push_back) will overwrite the oldest element.
+  #[inline(always)]
+  pub const fn is_full(&self) -> bool {
+    self.length == N
+  }
+
+  #[inline(always)]
+  fn physical_index(&self, logical_index: usize) -> usize {
+    // `head` and `logical_index` are both less than `N` wherever this is called, so the sum can
+    // be wrapped with a single subtraction.
+    let index = self.head + logical_index;
+    if index >= N {
+      index - N
+    } else {
+      index
+    }
+  }
+
+  /// Appends `value` to the back of the StaticRingBuffer. If the StaticRingBuffer was already
+  /// full, the oldest element is removed to make room and returned in `Some`. Otherwise, returns
+  /// `None`. If `N` is 0, `value` itself is immediately returned in `Some`.
+  #[inline]
+  pub fn push_back(&mut self, value: T) -> Option<T> {
+    if N == 0 {
+      return Some(value);
+    }
+    if self.length < N {
+      let index = self.physical_index(self.length);
+      unsafe { self.data.get_unchecked_mut(index).write(value) };
+      self.length += 1;
+      None
+    } else {
+      let res = unsafe {
+        self
+          .data
+          .get_unchecked_mut(self.head)
+          .as_mut_ptr()
+          .replace(value)
+      };
+      self.head = self.physical_index(1);
+      Some(res)
+    }
+  }
+
+  /// Removes the oldest element from the StaticRingBuffer and returns it in `Some`, or returns
+  /// `None` if the StaticRingBuffer is empty.
+  #[inline]
+  pub fn pop_front(&mut self) -> Option<T> {
+    if self.length == 0 {
+      return None;
+    }
+    let res = unsafe { self.data.get_unchecked(self.head).read() };
+    self.head = self.physical_index(1);
+    self.length -= 1;
+    Some(res)
+  }
+
+  /// Returns a constant reference to the oldest element in the StaticRingBuffer in `Some`, or
+  /// `None` if it is empty.
+  #[inline(always)]
+  pub fn front(&self) -> Option<&T> {
+    self.get(0)
+  }
+
+  /// Returns a constant reference to the newest element in the StaticRingBuffer in `Some`, or
+  /// `None` if it is empty.
+  #[inline(always)]
+  pub fn back(&self) -> Option<&T> {
+    self.get(self.length.wrapping_sub(1))
+  }
+
+  /// Returns a constant reference to the element at logical position `index` (where 0 is the
+  /// oldest element) in `Some`, or `None` if `index` is out of bounds.
+  #[inline]
+  pub fn get(&self, index: usize) -> Option<&T> {
+    if index < self.length {
+      Some(unsafe {
+        self
+          .data
+          .get_unchecked(self.physical_index(index))
+          .get_ref()
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Returns the contents of the StaticRingBuffer as two slices which, when concatenated, contain
+  /// every element in logical order, from oldest to newest. The second slice is empty if the
+  /// elements don't currently wrap around the end of the underlying array.
+  #[inline]
+  pub fn as_slices(&self) -> (&[T], &[T]) {
+    let first_length = self.length.min(N - self.head);
+    let p = self.data.as_ptr() as *const T;
+    // Safety: both ranges lie within the array and contain only initialized elements.
+    unsafe {
+      (
+        slice::from_raw_parts(p.add(self.head), first_length),
+        slice::from_raw_parts(p, self.length - first_length),
+      )
+    }
+  }
+
+  /// Returns an iterator over constant references to the elements of the StaticRingBuffer in
+  /// logical order, from oldest to newest.
+  #[inline(always)]
+  pub fn iter(&self) -> Chain<slice::Iter<T>, slice::Iter<T>> {
+    let (first, second) = self.as_slices();
+    first.iter().chain(second.iter())
+  }
+
+  /// Removes and drops all of the elements in the StaticRingBuffer.
+  #[inline]
+  pub fn clear(&mut self) {
+    let (first_length, second_length) = {
+      let (first, second) = self.as_slices();
+      (first.len(), second.len())
+    };
+    let p = self.data.as_mut_ptr() as *mut T;
+    let head = self.head;
+    // As with `StaticVec::clear`, the length is reset before anything is dropped so that a
+    // panicking destructor can only cause leaks rather than double drops.
+    self.head = 0;
+    self.length = 0;
+    unsafe {
+      ptr::drop_in_place(slice::from_raw_parts_mut(p.add(head), first_length));
+      ptr::drop_in_place(slice::from_raw_parts_mut(p, second_length));
+    }
+  }
+}
+
+impl<T: Clone, const N: usize> Clone for StaticRingBuffer<T, N> {
+  #[inline]
+  fn clone(&self) -> Self {
+    let mut res = Self::new();
+    for item in self.iter() {
+      res.push_back(item.clone());
+    }
+    res
+  }
+}
+
+impl<T: Debug, const N: usize> Debug for StaticRingBuffer<T, N> {
+  #[inline(always)]
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    f.debug_list().entries(self.iter()).finish()
+  }
+}
+
+impl<T, const N: usize> Default for StaticRingBuffer<T, N> {
+  /// Calls `new`.
+  #[inline(always)]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, const N: usize> Drop for StaticRingBuffer<T, N> {
+  #[inline(always)]
+  fn drop(&mut self) {
+    self.clear();
+  }
+}
+
+impl<T, const N: usize> Extend<T> for StaticRingBuffer<T, N> {
+  /// Pushes each item yielded by `iter` to the back of the StaticRingBuffer in turn, overwriting
+  /// the oldest elements as necessary.
+  #[inline]
+  fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+    for item in iter {
+      self.push_back(item);
+    }
+  }
+}
+
+impl<'a, T: 'a, const N: usize> IntoIterator for &'a StaticRingBuffer<T, N> {
+  type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+  type Item = &'a T;
+  #[inline(always)]
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
@@ -1,6 +1,7 @@
 use crate::iterators::*;
 use crate::utils::partial_compare;
 use crate::StaticVec;
+use core::borrow::{Borrow, BorrowMut};
 use core::cmp::{Eq, Ord, Ordering, PartialEq};
 use core::fmt::{self, Debug, Formatter};
 use core::hash::{Hash, Hasher};
@@ -27,7 +28,7 @@ use core::marker::PhantomData;
 
 #[cfg(feature = "serde_support")]
 use serde::{
-  de::{SeqAccess, Visitor},
+  de::{Error, SeqAccess, Visitor},
   Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -45,6 +46,20 @@ impl<T, const N: usize> AsRef<[T]> for StaticVec<T, N> {
   }
 }
 
+impl<T, const N: usize> Borrow<[T]> for StaticVec<T, N> {
+  #[inline(always)]
+  fn borrow(&self) -> &[T] {
+    self.as_slice()
+  }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for StaticVec<T, N> {
+  #[inline(always)]
+  fn borrow_mut(&mut self) -> &mut [T] {
+    self.as_mut_slice()
+  }
+}
+
 impl<T: Clone, const N: usize> Clone for StaticVec<T, N> {
   #[inline]
   default fn clone(&self) -> Self {
@@ -459,6 +474,21 @@ impl<const N: usize> Read for StaticVec<u8, N> {
   }
 }
 
+impl<const N: usize> fmt::Write for StaticVec<u8, N> {
+  /// Appends as many of the UTF-8 bytes of `s` as will fit in the StaticVec's remaining
+  /// capacity, returning [`fmt::Error`](core::fmt::Error) if they did not all fit.
+  #[inline]
+  fn write_str(&mut self, s: &str) -> fmt::Result {
+    let old_length = self.length;
+    self.extend_from_slice(s.as_bytes());
+    if self.length - old_length == s.len() {
+      Ok(())
+    } else {
+      Err(fmt::Error)
+    }
+  }
+}
+
 #[cfg(feature = "std")]
 impl<const N: usize> Write for StaticVec<u8, N> {
   #[inline]
@@ -522,13 +552,16 @@ where T: Deserialize<'de>
       fn visit_seq<SA>(self, mut seq: SA) -> Result<Self::Value, SA::Error>
       where SA: SeqAccess<'de> {
         let mut res = Self::Value::new();
-        while res.length < N {
-          if let Some(val) = seq.next_element()? {
-            unsafe {
-              res.push_unchecked(val);
-            }
-          } else {
-            break;
+        while let Some(val) = seq.next_element()? {
+          // Silently dropping the rest of the input here would lose data, so we error instead.
+          if res.length == N {
+            return Err(SA::Error::invalid_length(
+              N + 1 + seq.size_hint().unwrap_or(0),
+              &self,
+            ));
+          }
+          unsafe {
+            res.push_unchecked(val);
           }
         }
         Ok(res)
@@ -1,6 +1,7 @@
 use crate::StaticVec;
 use core::cmp::{Ordering, PartialOrd};
 use core::intrinsics;
+use core::ops::{Bound::Excluded, Bound::Included, Bound::Unbounded, RangeBounds};
 
 #[inline(always)]
 pub(crate) const fn distance_between<T>(dest: *const T, origin: *const T) -> usize {
@@ -15,6 +16,24 @@ pub(crate) const fn distance_between<T>(dest: *const T, origin: *const T) -> usi
   }
 }
 
+/// Converts `range` into a pair of `start` and `end` indices, treating an unbounded end as
+/// `length`. Borrowed this from normal Vec's implementation of `drain`.
+#[inline(always)]
+pub(crate) fn range_to_indices<R>(range: R, length: usize) -> (usize, usize)
+where R: RangeBounds<usize> {
+  let start = match range.start_bound() {
+    Included(&idx) => idx,
+    Excluded(&idx) => idx + 1,
+    Unbounded => 0,
+  };
+  let end = match range.end_bound() {
+    Included(&idx) => idx + 1,
+    Excluded(&idx) => idx,
+    Unbounded => length,
+  };
+  (start, end)
+}
+
 #[inline(always)]
 pub(crate) fn reverse_copy<T>(first: *const T, mut last: *const T, mut result: *mut T)
 where T: Copy {
@@ -29,19 +48,8 @@ where T: Copy {
 
 #[inline(always)]
 pub fn new_from_value<T, const COUNT: usize>(value: T) -> StaticVec<T, COUNT>
-where T: Copy {
-  StaticVec {
-    data: {
-      unsafe {
-        let mut data = StaticVec::new_data_uninit();
-        for i in 0..COUNT {
-          (data.as_mut_ptr() as *mut T).add(i).write(value);
-        }
-        data.assume_init()
-      }
-    },
-    length: COUNT,
-  }
+where T: Clone {
+  StaticVec::filled_with(|| value.clone())
 }
 
 #[inline]
@@ -63,3 +71,95 @@ pub(crate) fn partial_compare<T1, T2: PartialOrd<T1>>(
   }
   this.len().partial_cmp(&other.len())
 }
+
+const WORD_SIZE: usize = core::mem::size_of::<usize>();
+const LO_BYTES: usize = usize::max_value() / 255;
+const HI_BYTES: usize = LO_BYTES << 7;
+
+/// Returns true if any of the bytes in `word` are zero, using the classic
+/// "determine if a word has a zero byte" bit trick.
+#[inline(always)]
+const fn contains_zero_byte(word: usize) -> bool {
+  word.wrapping_sub(LO_BYTES) & !word & HI_BYTES != 0
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`, scanning a word at a
+/// time and only falling back to checking individual bytes within the word that matched, and
+/// in the final partial word.
+#[inline]
+pub(crate) fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+  let length = haystack.len();
+  let repeated = LO_BYTES * needle as usize;
+  let p = haystack.as_ptr();
+  let mut i = 0;
+  while i + WORD_SIZE <= length {
+    let word = unsafe { p.add(i).cast::<usize>().read_unaligned() };
+    if contains_zero_byte(word ^ repeated) {
+      break;
+    }
+    i += WORD_SIZE;
+  }
+  haystack[i..]
+    .iter()
+    .position(|&byte| byte == needle)
+    .map(|pos| i + pos)
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`, using the same technique
+/// as `find_byte` but scanning from the end.
+#[inline]
+pub(crate) fn rfind_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+  let repeated = LO_BYTES * needle as usize;
+  let p = haystack.as_ptr();
+  let mut end = haystack.len();
+  while end >= WORD_SIZE {
+    let word = unsafe { p.add(end - WORD_SIZE).cast::<usize>().read_unaligned() };
+    if contains_zero_byte(word ^ repeated) {
+      break;
+    }
+    end -= WORD_SIZE;
+  }
+  haystack[..end].iter().rposition(|&byte| byte == needle)
+}
+
+/// Implements the search behind [`find_subslice`](crate::StaticVec::find_subslice). The default
+/// implementation is a naive search, while the one for `u8` uses `find_byte` to skip directly to
+/// candidate positions. A smarter algorithm for any particular type can be added here as another
+/// specialized impl without touching `find_subslice` itself.
+pub(crate) trait SubsliceSearch: PartialEq + Sized {
+  fn find_subslice(haystack: &[Self], needle: &[Self]) -> Option<usize>;
+}
+
+impl<T: PartialEq> SubsliceSearch for T {
+  #[inline]
+  default fn find_subslice(haystack: &[T], needle: &[T]) -> Option<usize> {
+    if needle.len() > haystack.len() {
+      return None;
+    }
+    (0..=haystack.len() - needle.len()).find(|&i| haystack[i..i + needle.len()] == *needle)
+  }
+}
+
+impl SubsliceSearch for u8 {
+  #[inline]
+  fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let first = match needle.first() {
+      Some(&first) => first,
+      None => return Some(0),
+    };
+    if needle.len() > haystack.len() {
+      return None;
+    }
+    // Only positions at which the whole needle would still fit can hold a match.
+    let last_start = haystack.len() - needle.len();
+    let mut start = 0;
+    while let Some(pos) = find_byte(&haystack[start..=last_start], first) {
+      let candidate = start + pos;
+      if haystack[candidate..candidate + needle.len()] == *needle {
+        return Some(candidate);
+      }
+      start = candidate + 1;
+    }
+    None
+  }
+}
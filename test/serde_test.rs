@@ -0,0 +1,42 @@
+#![allow(clippy::all)]
+
+// These live apart from the main test suite, as merely linking `serde_json` brings enough
+// `PartialEq` impls into scope to break type inference for comparisons against `[]` there.
+
+use staticvec::*;
+
+#[test]
+fn deserialize() {
+  let v: StaticVec<i32, 4> = serde_json::from_str("[1, 2, 3]").unwrap();
+  assert_eq!(v, [1, 2, 3]);
+  let v2: StaticVec<String, 2> = serde_json::from_str(r#"["a", "b"]"#).unwrap();
+  assert_eq!(v2, [String::from("a"), String::from("b")]);
+  let v3: StaticVec<i32, 0> = serde_json::from_str("[]").unwrap();
+  assert!(v3.is_empty());
+}
+
+#[test]
+fn deserialize_over_capacity() {
+  let err = serde_json::from_str::<StaticVec<i32, 2>>("[1, 2, 3]").unwrap_err();
+  assert!(err.is_data());
+  assert!(err.to_string().contains("no more than 2 items"));
+  assert!(serde_json::from_str::<StaticVec<i32, 0>>("[1]").is_err());
+  assert!(serde_json::from_str::<StaticVec<String, 1>>(r#"["a", "b", "c"]"#).is_err());
+}
+
+#[test]
+fn serialize() {
+  let v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  let json = serde_json::to_string(&v).unwrap();
+  assert_eq!(json, "[1,2,3]");
+  let v2: StaticVec<i32, 8> = serde_json::from_str(&json).unwrap();
+  assert_eq!(v, v2);
+  let v3 = staticvec![String::from("x"), String::from("y")];
+  let v4: StaticVec<String, 2> =
+    serde_json::from_str(&serde_json::to_string(&v3).unwrap()).unwrap();
+  assert_eq!(v3, v4);
+  assert_eq!(
+    serde_json::to_string(&StaticVec::<i32, 4>::new()).unwrap(),
+    "[]"
+  );
+}
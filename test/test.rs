@@ -1,9 +1,11 @@
+#![feature(exact_size_is_empty, trusted_len)]
 #![allow(clippy::all)]
 #![allow(dead_code)]
 
 use staticvec::*;
 
 use core::cell;
+use core::cmp::Ordering;
 
 #[cfg(feature = "std")]
 use std::panic::{self, AssertUnwindSafe};
@@ -126,6 +128,78 @@ fn append() {
   assert_eq!(d, [12, 1]);
 }
 
+#[test]
+fn append_from_iter() {
+  let mut v = StaticVec::<i32, 6>::new_from_slice(&[1, 2]);
+  assert_eq!(v.append_from_iter(3..5), 2);
+  assert_eq!(v, [1, 2, 3, 4]);
+  assert_eq!(v.append_from_iter(5..7), 2);
+  assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+  assert_eq!(v.append_from_iter(7..10), 0);
+  let mut v2 = StaticVec::<i32, 4>::new();
+  let mut source = 1..10;
+  assert_eq!(v2.append_from_iter(&mut source), 4);
+  assert_eq!(v2, [1, 2, 3, 4]);
+  // Nothing beyond what fit was pulled from the iterator.
+  assert_eq!(source.next(), Some(5));
+  let lifespan_tracker = LifespanCounter::default();
+  let mut v3 = StaticVec::<LifespanCountingInstance, 2>::new();
+  let appended = v3.append_from_iter((0..5).map(|_| lifespan_tracker.instance()));
+  assert_eq!(appended, 2);
+  assert_eq!(lifespan_tracker.init_count(), 2);
+  drop(v3);
+  assert_eq!(lifespan_tracker.drop_count(), 2);
+}
+
+#[test]
+fn as_array() {
+  let v = staticvec![1, 2, 3];
+  assert_eq!(v.as_array(), Some(&[1, 2, 3]));
+  let mut v2 = StaticVec::<i32, 4>::new_from_slice(&[1, 2, 3]);
+  assert_eq!(v2.as_array(), None);
+  v2.push(4);
+  assert_eq!(v2.as_array(), Some(&[1, 2, 3, 4]));
+}
+
+#[test]
+fn as_bytes() {
+  let v = staticvec![1u32, 0x0102_0304, u32::max_value()];
+  let bytes = v.as_bytes();
+  assert_eq!(bytes.len(), 12);
+  assert_eq!(bytes[4..8], 0x0102_0304u32.to_ne_bytes());
+  let round_trip = StaticVec::<u32, 4>::try_from_bytes(bytes).unwrap();
+  assert_eq!(round_trip, v);
+  assert_eq!(round_trip.capacity(), 4);
+  let floats = staticvec![1.5f64, -2.0];
+  assert_eq!(
+    StaticVec::<f64, 2>::try_from_bytes(floats.as_bytes()),
+    Ok(floats)
+  );
+  let arrays = staticvec![[1u16, 2], [3, 4]];
+  assert_eq!(arrays.as_bytes().len(), 8);
+  assert_eq!(StaticVec::<u8, 4>::new().as_bytes(), []);
+}
+
+#[test]
+fn as_chunks_mut() {
+  let mut v = staticvec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+  let (chunks, remainder): (&mut [[i32; 4]], &mut [i32]) = v.as_chunks_mut();
+  assert_eq!(chunks.len(), 2);
+  assert_eq!(remainder.len(), 10 % 4);
+  for chunk in chunks.iter_mut() {
+    let sum: i32 = chunk.iter().sum();
+    for x in chunk.iter_mut() {
+      *x = sum;
+    }
+  }
+  remainder[0] = 0;
+  assert_eq!(v, [10, 10, 10, 10, 26, 26, 26, 26, 0, 10]);
+  let mut e = StaticVec::<i32, 4>::new_from_slice(&[1, 2]);
+  let (chunks, remainder): (&mut [[i32; 3]], &mut [i32]) = e.as_chunks_mut();
+  assert!(chunks.is_empty());
+  assert_eq!(remainder, [1, 2]);
+}
+
 #[test]
 fn as_mut_ptr() {
   let mut v = staticvec![1, 2, 3];
@@ -144,12 +218,154 @@ fn as_ptr() {
   unsafe { assert_eq!(*v.as_ptr(), 1) };
 }
 
+#[test]
+fn as_ref() {
+  fn byte_sum(bytes: impl AsRef<[u8]>) -> u32 {
+    bytes.as_ref().iter().map(|&b| b as u32).sum()
+  }
+  fn zero_out(mut bytes: impl AsMut<[u8]>) {
+    for b in bytes.as_mut() {
+      *b = 0;
+    }
+  }
+  let mut v = StaticVec::<u8, 8>::new_from_slice(&[1, 2, 3]);
+  assert_eq!(byte_sum(&v), 6);
+  zero_out(&mut v);
+  assert_eq!(byte_sum(v.clone()), 0);
+  assert_eq!(v, [0, 0, 0]);
+}
+
 #[test]
 fn as_slice() {
   let buffer = staticvec![1, 2, 3, 5, 8];
   assert_eq!(buffer.as_slice(), &[1, 2, 3, 5, 8]);
 }
 
+#[test]
+fn binary_insert() {
+  let mut v = StaticVec::<i32, 6>::new();
+  assert_eq!(v.binary_insert(5), Ok(0));
+  assert_eq!(v.binary_insert(1), Ok(0));
+  assert_eq!(v.binary_insert(9), Ok(2));
+  assert_eq!(v.binary_insert(3), Ok(1));
+  assert_eq!(v.binary_insert(5), Ok(3));
+  assert_eq!(v, [1, 3, 5, 5, 9]);
+  assert_eq!(v.binary_insert(10), Ok(5));
+  assert_eq!(v, [1, 3, 5, 5, 9, 10]);
+  assert_eq!(v.binary_insert(0), Err("Insufficient remaining capacity!"));
+  assert_eq!(v, [1, 3, 5, 5, 9, 10]);
+}
+
+#[test]
+fn binary_insert_by() {
+  let mut v = StaticVec::<i32, 4>::new();
+  for i in &[2, 8, 4, 6] {
+    assert!(v.binary_insert_by(*i, |a, b| b.cmp(a)).is_ok());
+  }
+  assert_eq!(v, [8, 6, 4, 2]);
+  assert!(v.binary_insert_by(5, |a, b| b.cmp(a)).is_err());
+}
+
+#[test]
+fn binary_insert_by_key() {
+  let mut v = StaticVec::<(i32, &str), 4>::new();
+  assert_eq!(v.binary_insert_by_key((2, "a"), |x| x.0), Ok(0));
+  assert_eq!(v.binary_insert_by_key((1, "b"), |x| x.0), Ok(0));
+  // Equal keys are inserted after the existing ones.
+  assert_eq!(v.binary_insert_by_key((2, "c"), |x| x.0), Ok(2));
+  assert_eq!(v.binary_insert_by_key((3, "d"), |x| x.0), Ok(3));
+  assert_eq!(v, [(1, "b"), (2, "a"), (2, "c"), (3, "d")]);
+  assert!(v.binary_insert_by_key((0, "e"), |x| x.0).is_err());
+}
+
+#[test]
+fn binary_search() {
+  let v = staticvec![1, 3, 5, 7, 9];
+  assert_eq!(v.binary_search(&5), Ok(2));
+  assert_eq!(v.binary_search(&4), Err(2));
+  assert_eq!(v.binary_search(&0), Err(0));
+  assert_eq!(v.binary_search(&10), Err(5));
+  assert_eq!(StaticVec::<i32, 4>::new().binary_search(&1), Err(0));
+}
+
+#[test]
+fn binary_search_by() {
+  let v = staticvec![9, 7, 5, 3, 1];
+  assert_eq!(v.binary_search_by(|x| 3.cmp(x)), Ok(3));
+  assert_eq!(v.binary_search_by(|x| 6.cmp(x)), Err(2));
+  assert_eq!(v.binary_search_by(|x| 10.cmp(x)), Err(0));
+  assert_eq!(v.binary_search_by(|x| 0.cmp(x)), Err(5));
+}
+
+#[test]
+fn binary_search_by_key() {
+  let v = staticvec![(0, 'a'), (2, 'b'), (4, 'c')];
+  assert_eq!(v.binary_search_by_key(&2, |&(a, _)| a), Ok(1));
+  assert_eq!(v.binary_search_by_key(&3, |&(a, _)| a), Err(2));
+  assert_eq!(v.binary_search_by_key(&-1, |&(a, _)| a), Err(0));
+  assert_eq!(v.binary_search_by_key(&5, |&(a, _)| a), Err(3));
+}
+
+#[test]
+fn array_chunks() {
+  let v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4, 5, 6]);
+  let mut sums = StaticVec::<i32, 3>::new();
+  let pairs: StaticVecArrayChunks<i32, 2> = v.array_chunks();
+  assert_eq!(pairs.len(), 3);
+  assert!(pairs.remainder().is_empty());
+  for &[a, b] in pairs {
+    sums.push(a + b);
+  }
+  assert_eq!(sums, [3, 7, 11]);
+  let mut quads: StaticVecArrayChunks<i32, 4> = v.array_chunks();
+  assert_eq!(quads.remainder(), [5, 6]);
+  assert_eq!(format!("{:?}", quads), "[[1, 2, 3, 4]]");
+  let &[a, _, _, d] = quads.next().unwrap();
+  assert_eq!((a, d), (1, 4));
+  assert!(quads.next().is_none());
+  assert_eq!(quads.remainder(), [5, 6]);
+  let mut triples: StaticVecArrayChunks<i32, 3> = v.array_chunks();
+  assert_eq!(triples.next_back(), Some(&[4, 5, 6]));
+  assert_eq!(triples.next_back(), Some(&[1, 2, 3]));
+  assert_eq!(triples.next_back(), None);
+  let sevens: StaticVecArrayChunks<i32, 7> = v.array_chunks();
+  assert_eq!(sevens.count(), 0);
+  let empty = StaticVec::<i32, 4>::new();
+  let empty_chunks: StaticVecArrayChunks<i32, 2> = empty.array_chunks();
+  assert!(empty_chunks.remainder().is_empty());
+  assert_eq!(empty_chunks.count(), 0);
+  let zsts = staticvec![(), (), (), (), ()];
+  let zst_chunks: StaticVecArrayChunks<(), 2> = zsts.array_chunks();
+  assert_eq!(zst_chunks.remainder().len(), 1);
+  assert_eq!(zst_chunks.count(), 2);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVecArrayChunks<i32, 0> = v.array_chunks();
+    });
+  }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn borrow() {
+  use std::borrow::{Borrow, BorrowMut};
+  use std::collections::HashMap;
+  let mut map = HashMap::new();
+  map.insert(StaticVec::<u8, 8>::new_from_slice(b"abc"), 1);
+  map.insert(StaticVec::<u8, 8>::new_from_slice(b"de"), 2);
+  assert_eq!(map.get(&b"abc"[..]), Some(&1));
+  assert_eq!(map.get(&b"de"[..]), Some(&2));
+  assert_eq!(map.get(&b"xyz"[..]), None);
+  let mut v = staticvec![1, 2, 3];
+  let s: &[i32] = v.borrow();
+  assert_eq!(s, [1, 2, 3]);
+  let sm: &mut [i32] = v.borrow_mut();
+  sm[0] = 4;
+  assert_eq!(v, [4, 2, 3]);
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn bounds_to_string() {
@@ -184,6 +400,68 @@ fn clear() {
   assert!(v.is_empty());
 }
 
+#[test]
+fn chunks() {
+  let v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  let mut it = v.chunks(2);
+  assert_eq!(it.next().unwrap(), [1, 2]);
+  assert_eq!(it.next().unwrap(), [3, 4]);
+  assert_eq!(it.next().unwrap(), [5]);
+  assert_eq!(it.next(), None);
+  assert_eq!(v.chunks(8).count(), 1);
+  assert_eq!(StaticVec::<i32, 8>::new().chunks(3).count(), 0);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(v.chunks(0));
+  }
+}
+
+#[test]
+fn chunks_exact() {
+  let v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  let mut it = v.chunks_exact(2);
+  assert_eq!(it.next().unwrap(), [1, 2]);
+  assert_eq!(it.next().unwrap(), [3, 4]);
+  assert_eq!(it.next(), None);
+  assert_eq!(it.remainder(), [5]);
+  assert_eq!(v.chunks_exact(6).count(), 0);
+}
+
+#[test]
+fn chunks_mut() {
+  let mut v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4, 5, 6, 7]);
+  for chunk in v.chunks_mut(3) {
+    chunk.reverse();
+  }
+  assert_eq!(v, [3, 2, 1, 6, 5, 4, 7]);
+}
+
+#[test]
+fn clear_from() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  v.clear_from(5);
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+  v.clear_from(2);
+  assert_eq!(v, [1, 2]);
+  v.clear_from(0);
+  assert_eq!(v, []);
+  let c = LifespanCounter::default();
+  let mut v2 = staticvec![c.instance(), c.instance(), c.instance()];
+  v2.clear_from(1);
+  assert_eq!(c.drop_count(), 2);
+  drop(v2);
+  assert_eq!(c.drop_count(), 3);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v3 = staticvec![1, 2, 3];
+      v3.clear_from(4);
+    });
+  }
+}
+
 #[test]
 fn clone() {
   let v = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
@@ -191,6 +469,52 @@ fn clone() {
   assert_eq!(v, vv);
 }
 
+#[test]
+fn clone_from_equal() {
+  let src: StaticVec<u32, { 20 }> = (5..15).collect();
+  let mut dst: StaticVec<u32, { 20 }> = (0..10).collect();
+  dst.clone_from(&src);
+  assert_eq!(dst, src);
+}
+
+#[test]
+fn clone_from_drop_counts() {
+  let lifespan_tracker = LifespanCounter::default();
+  let src: StaticVec<LifespanCountingInstance, 6> =
+    (0..3).map(|_| lifespan_tracker.instance()).collect();
+  // `self` longer than `source`: the two extra elements are dropped, and the remaining three are
+  // clone-assigned (which for this type is one clone and one drop each).
+  let mut dst: StaticVec<LifespanCountingInstance, 6> =
+    (0..5).map(|_| lifespan_tracker.instance()).collect();
+  assert_eq!(lifespan_tracker.init_count(), 8);
+  dst.clone_from(&src);
+  assert_eq!(dst.len(), 3);
+  assert_eq!(lifespan_tracker.init_count(), 11);
+  assert_eq!(lifespan_tracker.drop_count(), 5);
+  // Equal lengths: only clone-assignment happens.
+  dst.clone_from(&src);
+  assert_eq!(lifespan_tracker.init_count(), 14);
+  assert_eq!(lifespan_tracker.drop_count(), 8);
+  // `self` shorter than `source`: one clone-assignment, and two new clones with nothing dropped
+  // for them.
+  dst.truncate(1);
+  assert_eq!(lifespan_tracker.drop_count(), 10);
+  dst.clone_from(&src);
+  assert_eq!(dst.len(), 3);
+  assert_eq!(lifespan_tracker.init_count(), 17);
+  assert_eq!(lifespan_tracker.drop_count(), 11);
+  drop(dst);
+  drop(src);
+  assert_eq!(lifespan_tracker.init_count(), lifespan_tracker.drop_count());
+  // Existing elements are reused in place, so e.g. a `String`'s buffer survives.
+  let mut strings = staticvec![String::with_capacity(64), String::new()];
+  strings[0].push_str("old");
+  let capacity = strings[0].capacity();
+  strings.clone_from(&staticvec![String::from("new"), String::from("b")]);
+  assert_eq!(strings, ["new", "b"]);
+  assert_eq!(strings[0].capacity(), capacity);
+}
+
 #[test]
 fn clone_from_shorter() {
   let src: StaticVec<u32, { 20 }> = (1..10).collect();
@@ -277,6 +601,174 @@ fn panicking_clone() {
   assert_eq!(lifespan_tracker.drop_count(), 11);
 }
 
+#[test]
+fn clone_from_slice() {
+  let mut v = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  v.clone_from_slice(&[String::from("x"), String::from("y")]);
+  assert_eq!(v, ["x", "y", "c"]);
+  assert_eq!(v.len(), 3);
+  v.clone_from_slice(&[]);
+  assert_eq!(v, ["x", "y", "c"]);
+  let c = LifespanCounter::default();
+  {
+    let mut v2 = staticvec![c.instance(), c.instance(), c.instance()];
+    let src = [c.instance(), c.instance()];
+    v2.clone_from_slice(&src);
+    assert_eq!(v2.len(), 3);
+    assert_eq!(c.init_count(), 7);
+    assert_eq!(c.drop_count(), 2);
+  }
+  assert_eq!(c.drop_count(), 7);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![String::new()].clone_from_slice(&[String::new(), String::new()]));
+  }
+}
+
+#[test]
+fn clone_from_slice_new() {
+  let v = StaticVec::<String, 4>::clone_from_slice_new(&[String::from("a"), String::from("b")]);
+  assert_eq!(v, ["a", "b"]);
+  assert_eq!(v.capacity(), 4);
+  let v2 = StaticVec::<String, 1>::clone_from_slice_new(&[String::from("a"), String::from("b")]);
+  assert_eq!(v2, ["a"]);
+  #[cfg(feature = "std")]
+  {
+    #[derive(Debug)]
+    struct PanicOnClone<'a>(LifespanCountingInstance<'a>, bool);
+    impl<'a> Clone for PanicOnClone<'a> {
+      fn clone(&self) -> Self {
+        if self.1 {
+          panic!("Clone correctly panicked during a test")
+        }
+        PanicOnClone(self.0.clone(), self.1)
+      }
+    }
+    let lifespan_tracker = LifespanCounter::default();
+    let source = [
+      PanicOnClone(lifespan_tracker.instance(), false),
+      PanicOnClone(lifespan_tracker.instance(), false),
+      PanicOnClone(lifespan_tracker.instance(), true),
+    ];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+      StaticVec::<PanicOnClone, 4>::clone_from_slice_new(&source)
+    }));
+    assert!(result.is_err());
+    // The two successful clones must have been dropped during unwinding.
+    assert_eq!(lifespan_tracker.init_count(), 5);
+    assert_eq!(lifespan_tracker.drop_count(), 2);
+    drop(source);
+    assert_eq!(lifespan_tracker.drop_count(), 5);
+  }
+}
+
+#[test]
+fn clone_into() {
+  let mut v = StaticVec::<String, 4>::new();
+  v.push(String::from("a"));
+  v.push(String::from("b"));
+  v.push(String::from("c"));
+  let mut v2: StaticVec<String, 8> = v.clone_into();
+  assert_eq!(v2, ["a", "b", "c"]);
+  assert_eq!(v2.capacity(), 8);
+  v2[0].push_str("bc");
+  v2.push(String::from("d"));
+  assert_eq!(v, ["a", "b", "c"]);
+  assert_eq!(v2, ["abc", "b", "c", "d"]);
+  let v3: StaticVec<String, 3> = v.clone_into();
+  assert_eq!(v3, v);
+  #[cfg(feature = "std")]
+  {
+    #[derive(Debug)]
+    struct PanicOnClone<'a>(LifespanCountingInstance<'a>, bool);
+    impl<'a> Clone for PanicOnClone<'a> {
+      fn clone(&self) -> Self {
+        if self.1 {
+          panic!("Clone correctly panicked during a test")
+        }
+        PanicOnClone(self.0.clone(), self.1)
+      }
+    }
+    let lifespan_tracker = LifespanCounter::default();
+    let source = staticvec![
+      PanicOnClone(lifespan_tracker.instance(), false),
+      PanicOnClone(lifespan_tracker.instance(), false),
+      PanicOnClone(lifespan_tracker.instance(), true),
+    ];
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+      let res: StaticVec<PanicOnClone, 8> = source.clone_into();
+      res
+    }));
+    assert!(result.is_err());
+    // The two successful clones must have been dropped during unwinding.
+    assert_eq!(lifespan_tracker.init_count(), 5);
+    assert_eq!(lifespan_tracker.drop_count(), 2);
+    drop(source);
+    assert_eq!(lifespan_tracker.drop_count(), 5);
+  }
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _v4: StaticVec<String, 2> = v.clone_into();
+    });
+  }
+}
+
+#[test]
+fn cloned() {
+  let v = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  let c = v.cloned();
+  assert_eq!(c, v);
+  assert_eq!(c.capacity(), 3);
+  let lifespan_tracker = LifespanCounter::default();
+  let v2 = staticvec![lifespan_tracker.instance(), lifespan_tracker.instance()];
+  let c2 = v2.cloned();
+  assert_eq!(c2.len(), 2);
+  assert_eq!(lifespan_tracker.init_count(), 4);
+  drop(c2);
+  assert_eq!(lifespan_tracker.drop_count(), 2);
+}
+
+#[test]
+fn concat() {
+  let v = staticvec![
+    staticvec![1, 2],
+    StaticVec::new_from_slice(&[3]),
+    StaticVec::<i32, 2>::new(),
+    staticvec![4, 5]
+  ];
+  let c: StaticVec<i32, 6> = v.concat();
+  assert_eq!(c, [1, 2, 3, 4, 5]);
+  let c2: StaticVec<i32, 5> = v.concat();
+  assert_eq!(c2, [1, 2, 3, 4, 5]);
+  let words = staticvec!["ab".as_bytes(), "cd".as_bytes()];
+  let c3: StaticVec<u8, 4> = words.concat();
+  assert_eq!(c3, *b"abcd");
+  let empty = StaticVec::<[i32; 2], 4>::new();
+  let c4: StaticVec<i32, 0> = empty.concat();
+  assert!(c4.is_empty());
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<i32, 4> = v.concat();
+    });
+  }
+}
+
+#[test]
+fn contains() {
+  let v = staticvec![1, 2, 3];
+  assert!(v.contains(&2));
+  assert!(!v.contains(&4));
+  let mut v2 = StaticVec::<i32, 4>::new_from_slice(&[1, 2, 3, 4]);
+  v2.pop();
+  assert!(!v2.contains(&4));
+  assert!(!StaticVec::<i32, 4>::new().contains(&0));
+}
+
 #[test]
 fn dedup() {
   let mut vec = staticvec![1, 2, 2, 3, 2];
@@ -284,6 +776,94 @@ fn dedup() {
   assert_eq!(vec, [1, 2, 3, 2]);
 }
 
+#[test]
+fn copy_from_slice() {
+  let mut v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4]);
+  v.copy_from_slice(&[9, 8]);
+  assert_eq!(v, [9, 8, 3, 4]);
+  v.copy_from_slice(&[5, 6, 7, 8]);
+  assert_eq!(v, [5, 6, 7, 8]);
+  assert_eq!(v.len(), 4);
+  v.copy_from_slice(&[]);
+  assert_eq!(v, [5, 6, 7, 8]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    // The limit is the length, not the capacity.
+    assert_panics!(v.clone().copy_from_slice(&[1, 2, 3, 4, 5]));
+  }
+}
+
+#[test]
+fn contains_subslice() {
+  let v = staticvec!["a", "b", "c"];
+  assert!(v.contains_subslice(&["b", "c"]));
+  assert!(v.contains_subslice(&[]));
+  assert!(!v.contains_subslice(&["c", "b"]));
+  let bytes = StaticVec::<u8, 8>::new_from_slice(b"\x00\x01\xFF\x02");
+  assert!(bytes.contains_subslice(b"\xFF\x02"));
+  assert!(!bytes.contains_subslice(b"\x02\x00"));
+}
+
+#[test]
+fn copied_array() {
+  let mut v = StaticVec::<i32, 3>::new();
+  assert_eq!(v.copied_array(), None);
+  v.push(1);
+  v.push(2);
+  assert_eq!(v.copied_array(), None);
+  v.push(3);
+  let mut array = v.copied_array().unwrap();
+  assert_eq!(array, [1, 2, 3]);
+  array[0] = 10;
+  assert_eq!(v, [1, 2, 3]);
+  assert_eq!(StaticVec::<u8, 0>::new().copied_array(), Some([]));
+}
+
+#[test]
+fn count() {
+  let v = staticvec![1, 4, 7, 8, 10, 13, 2];
+  assert_eq!(v.count(|&x| x % 2 == 0), 4);
+  assert_eq!(v.count(|&x| x > 100), 0);
+  assert_eq!(v.count(|_| true), v.len());
+  assert_eq!(StaticVec::<i32, 4>::new().count(|_| true), 0);
+}
+
+#[test]
+fn dedup_and_collect() {
+  let mut v = staticvec![1, 2, 2, 2, 3, 1, 1];
+  let dups: StaticVec<i32, 3> = v.dedup_and_collect();
+  assert_eq!(v, [1, 2, 3, 1]);
+  assert_eq!(dups, [2, 2, 1]);
+  let mut v2 = staticvec![
+    String::from("a"),
+    String::from("a"),
+    String::from("b"),
+    String::from("b")
+  ];
+  let dups2: StaticVec<String, 2> = v2.dedup_and_collect();
+  assert_eq!(v2, [String::from("a"), String::from("b")]);
+  assert_eq!(dups2, [String::from("a"), String::from("b")]);
+  let mut v3 = staticvec![1, 2, 3];
+  let dups3: StaticVec<i32, 0> = v3.dedup_and_collect();
+  assert_eq!(v3, [1, 2, 3]);
+  assert!(dups3.is_empty());
+  let mut v4 = StaticVec::<i32, 4>::new();
+  let dups4: StaticVec<i32, 4> = v4.dedup_and_collect();
+  assert!(v4.is_empty() && dups4.is_empty());
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    let mut v5 = staticvec![7, 7, 7];
+    assert_panics!({
+      let _: StaticVec<i32, 1> = v5.clone().dedup_and_collect();
+    });
+    let dups5: StaticVec<i32, 2> = v5.dedup_and_collect();
+    assert_eq!(v5, [7]);
+    assert_eq!(dups5, [7, 7]);
+  }
+}
+
 #[test]
 fn dedup_by() {
   let mut vec = staticvec!["foo", "bar", "Bar", "baz", "bar"];
@@ -298,25 +878,367 @@ fn dedup_by_key() {
   assert_eq!(vec, [10, 20, 30, 20]);
 }
 
+#[test]
+fn dedup_unsorted() {
+  let mut v = staticvec![3, 1, 3, 2, 1];
+  v.dedup_unsorted();
+  assert_eq!(v, [3, 1, 2]);
+  let mut v2 = staticvec![1, 2, 3];
+  v2.dedup_unsorted();
+  assert_eq!(v2, [1, 2, 3]);
+  let mut v3 = staticvec![7, 7, 7, 7];
+  v3.dedup_unsorted();
+  assert_eq!(v3, [7]);
+  let mut v4 = StaticVec::<i32, 4>::new();
+  v4.dedup_unsorted();
+  assert_eq!(v4, []);
+  let mut strings = staticvec![
+    String::from("b"),
+    String::from("a"),
+    String::from("b"),
+    String::from("c"),
+    String::from("a")
+  ];
+  strings.dedup_unsorted();
+  assert_eq!(strings, ["b", "a", "c"]);
+}
+
+#[test]
+fn dedup_with_count() {
+  let mut v = staticvec![1, 1, 1, 2, 3, 3];
+  let runs: StaticVec<(i32, usize), 3> = v.dedup_with_count();
+  assert_eq!(runs, [(1, 3), (2, 1), (3, 2)]);
+  assert_eq!(v, [1, 2, 3]);
+  let mut v2 = staticvec!['a', 'b', 'a', 'a'];
+  let runs2: StaticVec<(char, usize), 4> = v2.dedup_with_count();
+  assert_eq!(runs2, [('a', 1), ('b', 1), ('a', 2)]);
+  assert_eq!(v2, ['a', 'b', 'a']);
+  let mut empty = StaticVec::<i32, 4>::new();
+  let runs3: StaticVec<(i32, usize), 0> = empty.dedup_with_count();
+  assert_eq!(runs3, []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    let v3 = staticvec![1, 2, 2, 3];
+    assert_panics!({
+      let _: StaticVec<(i32, usize), 2> = v3.clone().dedup_with_count();
+    });
+    assert_eq!(v3, [1, 2, 2, 3]);
+  }
+}
+
+#[test]
+fn difference() {
+  let a = staticvec![1, 3, 5, 7, 9];
+  let overlapping: StaticVec<i32, 5> = a.difference(&staticvec![3, 4, 5, 10]);
+  assert_eq!(overlapping, [1, 7, 9]);
+  let disjoint: StaticVec<i32, 5> = a.difference(&staticvec![2, 4]);
+  assert_eq!(disjoint, [1, 3, 5, 7, 9]);
+  let subset: StaticVec<i32, 5> = a.difference(&staticvec![1, 5, 9]);
+  assert_eq!(subset, [3, 7]);
+  let superset: StaticVec<i32, 5> = staticvec![3, 5].difference(&a);
+  assert_eq!(superset, []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<i32, 2> = staticvec![1, 2, 3].difference(&staticvec![4]);
+    });
+  }
+}
+
+#[test]
+fn default() {
+  // `Struct` does not implement `Default`, but an empty StaticVec of it still can.
+  #[derive(Default)]
+  struct Aggregate {
+    values: StaticVec<Struct, 4>,
+    count: usize,
+  }
+  let a = Aggregate::default();
+  assert!(a.values.is_empty());
+  assert_eq!(a.values.capacity(), 4);
+  assert_eq!(a.count, 0);
+  let v: StaticVec<i32, 0> = Default::default();
+  assert!(v.is_empty());
+}
+
 #[test]
 fn drain() {
   let mut v = staticvec![1, 2, 3];
-  let u = v.drain(1..);
+  let u: StaticVec<i32, 3> = v.drain(1..).collect();
   assert_eq!(v, &[1]);
   assert_eq!(u, &[2, 3]);
   v.drain(..);
   assert_eq!(v, &[]);
+  let mut v2 = staticvec![1, 2, 3, 4, 5, 6];
+  let mut d = v2.drain(1..4);
+  assert_eq!(d.len(), 3);
+  assert_eq!(d.next(), Some(2));
+  assert_eq!(d.next_back(), Some(4));
+  assert_eq!("[3]", format!("{:?}", d));
+  drop(d);
+  assert_eq!(v2, [1, 5, 6]);
+  let mut v3 = staticvec![(), (), (), ()];
+  assert_eq!(v3.drain(1..=2).count(), 2);
+  assert_eq!(v3.len(), 2);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v = staticvec![1, 2, 3];
+      v.drain(2..4);
+    });
+  }
+}
+
+#[test]
+fn drain_drop() {
+  let lifespan_tracker = LifespanCounter::default();
+  let mut v = StaticVec::<LifespanCountingInstance, 8>::new();
+  for _ in 0..8 {
+    v.push(lifespan_tracker.instance());
+  }
+  // Partially consuming the iterator must still drop every drained element exactly once.
+  let mut d = v.drain(2..6);
+  drop(d.next().unwrap());
+  assert_eq!(lifespan_tracker.drop_count(), 1);
+  drop(d);
+  assert_eq!(lifespan_tracker.drop_count(), 4);
+  assert_eq!(v.len(), 4);
+  // Leaking the iterator leaves the StaticVec holding only the elements before the range.
+  std::mem::forget(v.drain(1..2));
+  assert_eq!(v.len(), 1);
+  drop(v);
+  assert_eq!(lifespan_tracker.drop_count(), 5);
 }
 
 #[test]
 fn drain_filter() {
   let mut numbers = staticvec![1, 2, 3, 4, 5, 6, 8, 9, 11, 13, 14, 15];
-  let evens = numbers.drain_filter(|x| *x % 2 == 0);
+  let evens: StaticVec<i32, 12> = numbers.drain_filter(|x| *x % 2 == 0).collect();
   let odds = numbers;
   assert_eq!(evens, [2, 4, 6, 8, 14]);
   assert_eq!(odds, [1, 3, 5, 9, 11, 13, 15]);
 }
 
+#[test]
+fn drain_filter_lazy() {
+  let mut calls = 0;
+  let mut v = staticvec![1, 2, 3, 4, 5, 6];
+  {
+    let mut it = v.drain_filter(|x| {
+      calls += 1;
+      *x % 2 == 0
+    });
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.size_hint(), (0, Some(4)));
+  }
+  // Dropping the partially consumed iterator finishes the job.
+  assert_eq!(calls, 6);
+  assert_eq!(v, [1, 3, 5]);
+  let mut v2 = staticvec![1, 2, 3, 4, 5, 6];
+  let all: StaticVec<i32, 6> = v2.drain_filter(|x| *x % 2 == 0).collect();
+  assert_eq!(all, [2, 4, 6]);
+  assert_eq!(v2, v);
+  // Mutations made by the predicate to kept elements persist.
+  let mut v3 = staticvec![1, 2, 3, 4];
+  v3.drain_filter(|x| {
+    *x *= 10;
+    *x > 20
+  })
+  .for_each(drop);
+  assert_eq!(v3, [10, 20]);
+}
+
+#[test]
+fn drain_filter_drop_counts() {
+  let c = LifespanCounter::default();
+  {
+    let mut v: StaticVec<LifespanCountingInstance, 8> = (0..6).map(|_| c.instance()).collect();
+    let mut i = 0;
+    let mut it = v.drain_filter(|_| {
+      i += 1;
+      i % 3 == 0
+    });
+    let first = it.next();
+    assert!(first.is_some());
+    drop(it);
+    // The other matching element was dropped along with the iterator.
+    assert_eq!(c.drop_count(), 1);
+    assert_eq!(v.len(), 4);
+    drop(first);
+    assert_eq!(c.drop_count(), 2);
+  }
+  assert_eq!(c.init_count(), 6);
+  assert_eq!(c.drop_count(), 6);
+  // Leaking the iterator leaks the elements rather than double-dropping them.
+  let c2 = LifespanCounter::default();
+  let mut v2: StaticVec<LifespanCountingInstance, 4> = (0..4).map(|_| c2.instance()).collect();
+  core::mem::forget(v2.drain_filter(|_| true));
+  assert_eq!(v2.len(), 0);
+  drop(v2);
+  assert_eq!(c2.drop_count(), 0);
+  #[cfg(feature = "std")]
+  {
+    // A panicking predicate leaves the StaticVec with every element that wasn't removed.
+    let c3 = LifespanCounter::default();
+    let mut v3: StaticVec<LifespanCountingInstance, 5> = (0..5).map(|_| c3.instance()).collect();
+    let mut j = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+      v3.drain_filter(|_| {
+        j += 1;
+        if j == 4 {
+          panic!("Predicate correctly panicked during a test");
+        }
+        j == 2
+      })
+      .for_each(drop);
+    }));
+    assert!(result.is_err());
+    assert_eq!(v3.len(), 4);
+    assert_eq!(c3.drop_count(), 1);
+    drop(v3);
+    assert_eq!(c3.drop_count(), 5);
+  }
+}
+
+#[test]
+fn drop_counts() {
+  let c = LifespanCounter::default();
+  {
+    let mut v = StaticVec::<LifespanCountingInstance, 16>::new();
+    for _ in 0..10 {
+      v.push(c.instance());
+    }
+    // Popped elements are moved out, and dropped here by us.
+    drop(v.pop());
+    drop(v.pop());
+    assert_eq!(c.drop_count(), 2);
+    // Drained elements are dropped whether or not they're yielded.
+    let mut drain = v.drain(1..4);
+    drop(drain.next());
+    drop(drain);
+    assert_eq!(v.len(), 5);
+    assert_eq!(c.drop_count(), 5);
+    // Moved-out elements are not dropped by the StaticVec.
+    let removed = v.remove(0);
+    assert_eq!(c.drop_count(), 5);
+    v.truncate(3);
+    assert_eq!(c.drop_count(), 6);
+    v.push(c.instance());
+    assert_eq!(v.len(), 4);
+    // Dropping the StaticVec must drop exactly the 4 inhabited elements, and never touch the
+    // slots from `length` to `N`, some of which previously held now-dropped elements.
+    drop(v);
+    assert_eq!(c.drop_count(), 10);
+    drop(removed);
+  }
+  assert_eq!(c.init_count(), 11);
+  assert_eq!(c.drop_count(), 11);
+  // The same goes for a partially consumed by-value iterator.
+  let c2 = LifespanCounter::default();
+  {
+    let v: StaticVec<LifespanCountingInstance, 8> = (0..6).map(|_| c2.instance()).collect();
+    let mut it = v.into_iter();
+    drop(it.next());
+    drop(it.next_back());
+    assert_eq!(c2.drop_count(), 2);
+  }
+  assert_eq!(c2.init_count(), 6);
+  assert_eq!(c2.drop_count(), 6);
+  // And for an empty StaticVec, nothing is dropped at all.
+  let c3 = LifespanCounter::default();
+  drop(StaticVec::<LifespanCountingInstance, 4>::new());
+  assert_eq!(c3.drop_count(), 0);
+}
+
+#[test]
+fn drop_first() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  v.drop_first(0);
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+  v.drop_first(2);
+  assert_eq!(v, [3, 4, 5]);
+  v.drop_first(10);
+  assert_eq!(v, []);
+  let c = LifespanCounter::default();
+  let mut v2 = staticvec![c.instance(), c.instance(), c.instance(), c.instance()];
+  v2.drop_first(3);
+  assert_eq!(v2.len(), 1);
+  assert_eq!(c.drop_count(), 3);
+  drop(v2);
+  assert_eq!(c.init_count(), 4);
+  assert_eq!(c.drop_count(), 4);
+}
+
+#[test]
+fn each_mut() {
+  let mut v = staticvec![1, 2, 3];
+  let [a, b, c] = v.each_mut().unwrap();
+  *a += 10;
+  *b *= 2;
+  core::mem::swap(a, c);
+  assert_eq!(v, [3, 4, 11]);
+  let mut v2 = StaticVec::<i32, 3>::new_from_slice(&[1, 2]);
+  assert!(v2.each_mut().is_none());
+  let mut strings = staticvec![String::from("a"), String::from("b")];
+  for s in strings.each_mut().unwrap().iter_mut() {
+    s.push('!');
+  }
+  assert_eq!(strings, ["a!", "b!"]);
+}
+
+#[test]
+fn ends_with() {
+  let v = staticvec![1, 2, 3];
+  assert!(v.ends_with(&[]));
+  assert!(v.ends_with(&[3]));
+  assert!(v.ends_with(&[2, 3]));
+  assert!(v.ends_with(&[1, 2, 3]));
+  assert!(!v.ends_with(&[2]));
+  assert!(!v.ends_with(&[0, 1, 2, 3]));
+  assert!(StaticVec::<i32, 2>::new().ends_with(&[]));
+}
+
+#[test]
+fn exact_size_iterators() {
+  fn assert_trusted_len<I: core::iter::TrustedLen>(_: &I) {}
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  let mut it = v.iter();
+  assert_trusted_len(&it);
+  assert_eq!(it.len(), v.len());
+  assert_eq!(it.size_hint(), (5, Some(5)));
+  it.next();
+  it.next_back();
+  assert_eq!(it.len(), 3);
+  assert_eq!(it.size_hint(), (3, Some(3)));
+  it.by_ref().for_each(drop);
+  assert_eq!(it.len(), 0);
+  assert!(it.is_empty());
+  let mut itm = v.iter_mut();
+  assert_trusted_len(&itm);
+  itm.next();
+  assert_eq!(itm.len(), 4);
+  assert_eq!(itm.size_hint(), (4, Some(4)));
+  let mut iti = v.clone().into_iter();
+  assert_trusted_len(&iti);
+  iti.next_back();
+  iti.next_back();
+  assert_eq!(iti.len(), 3);
+  assert_eq!(iti.size_hint(), (3, Some(3)));
+  let mut itd = v.drain(1..4);
+  assert_trusted_len(&itd);
+  itd.next();
+  assert_eq!(itd.len(), 2);
+  assert_eq!(itd.size_hint(), (2, Some(2)));
+  let z = staticvec![(), (), ()];
+  let mut itz = z.iter();
+  itz.next();
+  assert_eq!(itz.len(), 2);
+  assert_eq!(itz.size_hint(), (2, Some(2)));
+}
+
 #[test]
 fn extend() {
   let mut c = StaticVec::<i32, 6>::new();
@@ -331,6 +1253,17 @@ fn extend() {
   assert_eq!(c.len(), 1);
   c.extend(staticvec![1, 2, 3, 4, 5, 6, 7].iter());
   assert_eq!(c.len(), 6);
+  let mut d = StaticVec::<Box<i32>, 4>::new();
+  d.extend(vec![Box::new(1), Box::new(2)]);
+  assert_eq!(d, [Box::new(1), Box::new(2)]);
+  d.extend(vec![Box::new(3), Box::new(4)]);
+  assert_eq!(d, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  d.clear();
+  let mut it = (1..8).map(Box::new);
+  d.extend(&mut it);
+  assert_eq!(d, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+  // Items past the remaining capacity are left in the source iterator.
+  assert_eq!(it.next(), Some(Box::new(5)));
 }
 
 #[test]
@@ -340,6 +1273,59 @@ fn extend_from_slice() {
   assert_eq!(vec, [1, 2, 3, 4]);
 }
 
+#[test]
+fn extend_from_within() {
+  let mut v = StaticVec::<i32, 12>::new_from_slice(&[1, 2, 3, 4]);
+  v.extend_from_within(1..3);
+  assert_eq!(v, [1, 2, 3, 4, 2, 3]);
+  v.extend_from_within(4..);
+  assert_eq!(v, [1, 2, 3, 4, 2, 3, 2, 3]);
+  v.extend_from_within(..=1);
+  assert_eq!(v, [1, 2, 3, 4, 2, 3, 2, 3, 1, 2]);
+  v.extend_from_within(10..);
+  assert_eq!(v.len(), 10);
+  v.extend_from_within(8..10);
+  assert_eq!(v, [1, 2, 3, 4, 2, 3, 2, 3, 1, 2, 1, 2]);
+  let mut v2 = StaticVec::<u8, 6>::new_from_slice(&[7]);
+  for _ in 0..2 {
+    v2.extend_from_within(..);
+  }
+  assert_eq!(v2, [7, 7, 7, 7]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(v2.clone().extend_from_within(..3));
+    assert_panics!(v2.clone().extend_from_within(3..5));
+    assert_panics!(v.clone().extend_from_within(0..1));
+  }
+}
+
+#[test]
+fn fill_remaining() {
+  let mut v = StaticVec::<String, 4>::new();
+  v.push(String::from("a"));
+  v.fill_remaining(String::from("pad"));
+  assert_eq!(v, ["a", "pad", "pad", "pad"]);
+  v.fill_remaining(String::from("x"));
+  assert_eq!(v, ["a", "pad", "pad", "pad"]);
+  let mut empty = StaticVec::<i32, 3>::new();
+  empty.fill_remaining(7);
+  assert_eq!(empty, [7, 7, 7]);
+}
+
+#[test]
+fn fill_remaining_with() {
+  let mut v = StaticVec::<i32, 5>::from([1, 2]);
+  let mut i = 2;
+  v.fill_remaining_with(|| {
+    i += 1;
+    i
+  });
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+  v.fill_remaining_with(|| panic!("should not be called on a full StaticVec"));
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+}
+
 #[test]
 fn filled_with() {
   let mut i = 0;
@@ -365,9 +1351,90 @@ fn filled_with_by_index() {
 }
 
 #[test]
-fn first() {
-  let v = staticvec![1, 2, 3];
-  assert_eq!(*v.first().unwrap(), 1);
+fn find_byte() {
+  for length in [0usize, 1, 7, 8, 9, 15, 16, 17, 31, 32, 33].iter().cloned() {
+    let mut v = StaticVec::<u8, 40>::new();
+    for i in 0..length {
+      v.push(i as u8 + 1);
+    }
+    assert_eq!(v.find_byte(0), None);
+    assert_eq!(v.find_byte(100), None);
+    for i in 0..length {
+      assert_eq!(v.find_byte(i as u8 + 1), Some(i));
+    }
+  }
+  let v = StaticVec::<u8, 20>::new_from_slice(b"abc,defghijkl,mnopq");
+  assert_eq!(v.find_byte(b','), Some(3));
+  assert_eq!(v.find_byte(b'a'), Some(0));
+  assert_eq!(v.find_byte(b'q'), Some(18));
+  assert_eq!(v.find_byte(b'z'), None);
+  assert_eq!(staticvec![0x80u8, 0xFF, 0x80].find_byte(0xFF), Some(1));
+}
+
+#[test]
+fn find_indices() {
+  let v = staticvec![3, 8, 1, 6, 6, 9, 2];
+  let evens: StaticVec<usize, 4> = v.find_indices(|&x| x % 2 == 0);
+  assert_eq!(evens, [1, 3, 4, 6]);
+  let none: StaticVec<usize, 1> = v.find_indices(|&x| x > 100);
+  assert_eq!(none, []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<usize, 3> = v.find_indices(|&x| x % 2 == 0);
+    });
+  }
+}
+
+#[test]
+fn find_map() {
+  let v = staticvec!["a", "1", "b", "2"];
+  let mut visited = 0;
+  let first_number = v.find_map(|s| {
+    visited += 1;
+    s.parse::<i32>().ok()
+  });
+  assert_eq!(first_number, Some(1));
+  assert_eq!(visited, 2);
+  assert_eq!(
+    v.find_map(|s| s.parse::<f32>().ok().filter(|&x| x > 5.0)),
+    None
+  );
+}
+
+#[test]
+fn find_subslice() {
+  let v = staticvec![1, 2, 3, 1, 2, 4];
+  assert_eq!(v.find_subslice(&[1, 2]), Some(0));
+  assert_eq!(v.find_subslice(&[1, 2, 4]), Some(3));
+  assert_eq!(v.find_subslice(&[2, 4]), Some(4));
+  assert_eq!(v.find_subslice(&[3, 1]), Some(2));
+  assert_eq!(v.find_subslice(&[4, 1]), None);
+  assert_eq!(v.find_subslice(&[]), Some(0));
+  assert_eq!(v.find_subslice(&[1, 2, 3, 1, 2, 4, 5]), None);
+  assert_eq!(StaticVec::<i32, 2>::new().find_subslice(&[]), Some(0));
+  let bytes = StaticVec::<u8, 32>::new_from_slice(b"GET /index.html HTTP/1.1\r\n\r\n");
+  assert_eq!(bytes.find_subslice(b"GET"), Some(0));
+  assert_eq!(bytes.find_subslice(b"HTTP"), Some(16));
+  assert_eq!(bytes.find_subslice(b"\r\n\r\n"), Some(24));
+  assert_eq!(bytes.find_subslice(b"\n\r\n"), Some(25));
+  assert_eq!(bytes.find_subslice(b"HTTP/2"), None);
+  assert_eq!(bytes.find_subslice(b""), Some(0));
+  assert_eq!(
+    StaticVec::<u8, 4>::new_from_slice(b"ab").find_subslice(b"abc"),
+    None
+  );
+  assert_eq!(
+    StaticVec::<u8, 4>::new_from_slice(b"aab").find_subslice(b"ab"),
+    Some(1)
+  );
+}
+
+#[test]
+fn first() {
+  let v = staticvec![1, 2, 3];
+  assert_eq!(*v.first().unwrap(), 1);
 }
 
 #[test]
@@ -376,6 +1443,71 @@ fn first_mut() {
   assert_eq!(*v.first_mut().unwrap(), 1);
 }
 
+#[test]
+fn first_chunk() {
+  let v = StaticVec::<u8, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  let header: Option<&[u8; 2]> = v.first_chunk();
+  assert_eq!(header, Some(&[1, 2]));
+  let all: Option<&[u8; 5]> = v.first_chunk();
+  assert_eq!(all, Some(&[1, 2, 3, 4, 5]));
+  let too_long: Option<&[u8; 6]> = v.first_chunk();
+  assert_eq!(too_long, None);
+  let empty = StaticVec::<u8, 2>::new();
+  let nothing: Option<&[u8; 0]> = empty.first_chunk();
+  assert_eq!(nothing, Some(&[]));
+  let triple: Option<&[u8; 3]> = v.first_chunk();
+  let &[a, b, c] = triple.unwrap();
+  assert_eq!((a, b, c), (1, 2, 3));
+}
+
+#[test]
+fn first_chunk_mut() {
+  let mut v = StaticVec::<u8, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  let header: Option<&mut [u8; 2]> = v.first_chunk_mut();
+  *header.unwrap() = [9, 8];
+  assert_eq!(v, [9, 8, 3, 4, 5]);
+  let too_long: Option<&mut [u8; 6]> = v.first_chunk_mut();
+  assert!(too_long.is_none());
+}
+
+#[test]
+fn fmt_write() {
+  // Called through the trait explicitly, as `std::io::Write` is also in scope here.
+  let mut v = StaticVec::<u8, 12>::new();
+  core::fmt::Write::write_fmt(&mut v, format_args!("{} {}", 42, "abc")).unwrap();
+  assert_eq!(v, *b"42 abc");
+  core::fmt::Write::write_str(&mut v, "").unwrap();
+  assert_eq!(v.len(), 6);
+  let mut v2 = StaticVec::<u8, 5>::new();
+  assert!(core::fmt::Write::write_fmt(&mut v2, format_args!("{}-{}", 123, "xyz")).is_err());
+  assert_eq!(v2, *b"123-x");
+  assert!(core::fmt::Write::write_char(&mut v2, 'q').is_err());
+  assert_eq!(v2, *b"123-x");
+  let mut v3 = StaticVec::<u8, 3>::new();
+  assert!(core::fmt::Write::write_char(&mut v3, 'é').is_ok());
+  assert_eq!(v3, [0xC3, 0xA9]);
+}
+
+#[test]
+fn for_each_chunk_mut() {
+  let mut v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  let mut lengths = StaticVec::<usize, 3>::new();
+  v.for_each_chunk_mut(3, |chunk| {
+    lengths.push(chunk.len());
+    chunk.reverse();
+  });
+  assert_eq!(lengths, [3, 3, 1]);
+  assert_eq!(v, [3, 2, 1, 6, 5, 4, 7]);
+  let mut calls = 0;
+  StaticVec::<i32, 4>::new().for_each_chunk_mut(2, |_| calls += 1);
+  assert_eq!(calls, 0);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![1, 2, 3].for_each_chunk_mut(0, |_| {}));
+  }
+}
+
 #[test]
 fn from() {
   assert_eq!(
@@ -396,6 +1528,141 @@ fn from() {
   );
 }
 
+#[test]
+fn from_iter() {
+  let v: StaticVec<i32, 8> = (0..5).collect();
+  assert_eq!(v, [0, 1, 2, 3, 4]);
+  let v2: StaticVec<i32, 5> = (0..5).collect();
+  assert_eq!(v2, [0, 1, 2, 3, 4]);
+  let v3: StaticVec<i32, 3> = (0..5).collect();
+  assert_eq!(v3, [0, 1, 2]);
+  let v4: StaticVec<i32, 0> = (0..5).collect();
+  assert_eq!(v4, []);
+  let lifespan_tracker = LifespanCounter::default();
+  let v5: StaticVec<LifespanCountingInstance, 3> =
+    (0..5).map(|_| lifespan_tracker.instance()).collect();
+  // Only the items that actually fit are ever pulled from the iterator.
+  assert_eq!(lifespan_tracker.init_count(), 3);
+  assert_eq!(lifespan_tracker.drop_count(), 0);
+  drop(v5);
+  assert_eq!(lifespan_tracker.drop_count(), 3);
+}
+
+#[test]
+fn from_packed_bits() {
+  let bits = staticvec![true, false, true, true, false, false, false, true, false, true, true];
+  let packed: StaticVec<u8, 2> = bits.to_packed_bits();
+  assert_eq!(packed, [0b1000_1101, 0b0000_0110]);
+  let unpacked = StaticVec::<bool, 11>::from_packed_bits(&packed, bits.len());
+  assert_eq!(unpacked, bits);
+  let partial = StaticVec::<bool, 16>::from_packed_bits(&packed, 3);
+  assert_eq!(partial, [true, false, true]);
+  let empty: StaticVec<u8, 0> = StaticVec::<bool, 4>::new().to_packed_bits();
+  assert_eq!(empty, []);
+  assert_eq!(StaticVec::<bool, 4>::from_packed_bits(&[], 0), []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _packed: StaticVec<u8, 1> = staticvec![false; 9].to_packed_bits();
+    });
+    assert_panics!(StaticVec::<bool, 16>::from_packed_bits(&[0xFF], 9));
+    assert_panics!(StaticVec::<bool, 4>::from_packed_bits(&[0xFF], 5));
+  }
+}
+
+#[test]
+fn from_repeating() {
+  let v = StaticVec::<i32, 8>::from_repeating(&[1, 2, 3], 2);
+  assert_eq!(v, [1, 2, 3, 1, 2, 3]);
+  assert_eq!(v.capacity(), 8);
+  let exact = StaticVec::<u8, 6>::from_repeating(&[0xA, 0xB], 3);
+  assert_eq!(exact, [0xA, 0xB, 0xA, 0xB, 0xA, 0xB]);
+  assert_eq!(StaticVec::<i32, 4>::from_repeating(&[1, 2], 0), []);
+  assert_eq!(StaticVec::<i32, 4>::from_repeating(&[], 10), []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(StaticVec::<i32, 5>::from_repeating(&[1, 2], 3));
+    assert_panics!(StaticVec::<i32, 5>::from_repeating(
+      &[1, 2],
+      usize::max_value()
+    ));
+  }
+}
+
+#[test]
+fn get() {
+  let v = StaticVec::<&str, 6>::new_from_slice(&["a", "b", "c"]);
+  assert_eq!(v.get(1), Some(&"b"));
+  assert_eq!(v.get(3), None);
+  assert_eq!(v.get(5), None);
+  assert_eq!(v.get(1..3), Some(&["b", "c"][..]));
+  assert_eq!(v.get(..), Some(&["a", "b", "c"][..]));
+  assert_eq!(v.get(2..4), None);
+  assert_eq!(v.get(3..), Some(&[][..]));
+}
+
+#[test]
+fn get_many_mut() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  if let Some([a, b, c]) = v.get_many_mut([4, 0, 2]) {
+    core::mem::swap(a, b);
+    *c += 10;
+  } else {
+    panic!("expected Some");
+  }
+  assert_eq!(v, [5, 2, 13, 4, 1]);
+  assert!(v.get_many_mut([1, 3, 1]).is_none());
+  assert!(v.get_many_mut([0, 5]).is_none());
+  assert!(v.get_many_mut([core::usize::MAX]).is_none());
+  assert_eq!(v.get_many_mut([]), Some([]));
+  let mut strings = staticvec![String::from("a"), String::from("b")];
+  let [x, y] = strings.get_many_mut([1, 0]).unwrap();
+  x.push_str(y);
+  assert_eq!(strings, ["a", "ba"]);
+  let mut empty = StaticVec::<i32, 4>::new();
+  assert!(empty.get_many_mut([0]).is_none());
+}
+
+#[test]
+fn get_mut() {
+  let mut v = StaticVec::<i32, 6>::new_from_slice(&[1, 2, 3]);
+  *v.get_mut(0).unwrap() = 4;
+  assert_eq!(v, [4, 2, 3]);
+  assert_eq!(v.get_mut(3), None);
+  for x in v.get_mut(1..).unwrap() {
+    *x *= 10;
+  }
+  assert_eq!(v, [4, 20, 30]);
+  assert_eq!(v.get_mut(..4), None);
+}
+
+#[test]
+fn get_or_insert_with() {
+  let mut map = StaticVec::<(&str, i32), 3>::new();
+  map.get_or_insert_with(|&(k, _)| k == "a", || ("a", 0)).1 += 1;
+  map.get_or_insert_with(|&(k, _)| k == "b", || ("b", 10)).1 += 1;
+  map.get_or_insert_with(|&(k, _)| k == "a", || ("a", 0)).1 += 1;
+  assert_eq!(map, [("a", 2), ("b", 11)]);
+  let found = map.get_or_insert_with(|&(k, _)| k == "b", || panic!("should not be called"));
+  assert_eq!(*found, ("b", 11));
+  map.get_or_insert_with(|&(k, _)| k == "c", || ("c", 5));
+  assert!(map.is_full());
+  assert_eq!(
+    *map.get_or_insert_with(|&(k, _)| k == "c", || ("c", 0)),
+    ("c", 5)
+  );
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut m = map.clone();
+      m.get_or_insert_with(|&(k, _)| k == "d", || ("d", 0));
+    });
+  }
+}
+
 #[test]
 fn get_unchecked() {
   let v = staticvec!["a", "b", "c"];
@@ -408,6 +1675,54 @@ fn get_unchecked_mut() {
   assert_eq!(unsafe { *v.get_unchecked_mut(1) }, "b");
 }
 
+#[test]
+fn group_by() {
+  let v = staticvec![1, 1, 2, 3, 3, 3];
+  let lengths: StaticVec<usize, 6> = v.group_by(|a, b| a == b).map(|g| g.len()).collect();
+  assert_eq!(lengths, [2, 1, 3]);
+  let mut groups = v.group_by(|a, b| a == b);
+  assert_eq!(groups.next().unwrap(), [1, 1]);
+  assert_eq!(groups.next().unwrap(), [2]);
+  assert_eq!(groups.next().unwrap(), [3, 3, 3]);
+  assert_eq!(groups.next(), None);
+  // Runs of strictly increasing values.
+  let v2 = staticvec![1, 2, 3, 2, 3, 1];
+  let runs: StaticVec<&[i32], 6> = v2.group_by(|a, b| a < b).collect();
+  assert_eq!(runs, [&[1, 2, 3][..], &[2, 3][..], &[1][..]]);
+  assert_eq!(
+    StaticVec::<i32, 4>::new().group_by(|a, b| a == b).count(),
+    0
+  );
+  assert_eq!(staticvec![5].group_by(|_, _| false).count(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash() {
+  use std::collections::hash_map::DefaultHasher;
+  use std::collections::HashSet;
+  use std::hash::{Hash, Hasher};
+  fn hash_of<H: Hash + ?Sized>(value: &H) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+  }
+  let v = StaticVec::<i32, 4>::new_from_slice(&[1, 2, 3]);
+  let v2 = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  assert_eq!(v, v2);
+  assert_eq!(hash_of(&v), hash_of(&v2));
+  assert_eq!(hash_of(&v), hash_of(&[1, 2, 3][..]));
+  assert_eq!(hash_of(&v), hash_of(&vec![1, 2, 3]));
+  assert_ne!(hash_of(&v), hash_of(&staticvec![1, 2]));
+  let mut set = HashSet::new();
+  set.insert(v);
+  set.insert(staticvec![4, 5, 6, 7]);
+  let collected: StaticVec<i32, 4> = staticvec![1, 2, 3, 0].into_iter().take(3).collect();
+  assert!(set.contains(&collected));
+  assert!(set.contains(&StaticVec::from([4, 5, 6, 7])));
+  assert!(!set.contains(&StaticVec::new()));
+}
+
 #[test]
 fn index() {
   let vec = staticvec![0, 1, 2, 3, 4];
@@ -417,6 +1732,15 @@ fn index() {
   assert_eq!(vec[1..3], [1, 2]);
   assert_eq!(vec[1..=3], [1, 2, 3]);
   assert_eq!(vec[..], [0, 1, 2, 3, 4]);
+  assert_eq!(vec[..2], [0, 1]);
+  assert_eq!(vec[..=2], [0, 1, 2]);
+  assert_eq!(vec[3..], [3, 4]);
+  assert_eq!(vec[2..2], []);
+  assert_eq!(vec[5..], []);
+  // Indexing is checked against the length, not the capacity.
+  let partial = StaticVec::<i32, 8>::new_from_slice(&[0, 1, 2]);
+  assert_eq!(partial[..], [0, 1, 2]);
+  assert_eq!(partial[3..3], []);
   // Because this block includes obviously-violated bounds checks, miri
   // complains about it
   #[cfg(not(miri))]
@@ -428,6 +1752,71 @@ fn index() {
     assert_panics!(&vec[10..]);
     assert_panics!(&vec[10..15]);
     assert_panics!(&vec[1..0]);
+    assert_panics!(&vec[..=5]);
+    assert_panics!(partial[3]);
+    assert_panics!(&partial[..4]);
+    assert_panics!(&partial[4..4], includes("out of range"));
+  }
+}
+
+#[test]
+fn index_mut() {
+  let mut vec = staticvec![0, 1, 2, 3, 4];
+  vec[0] = 10;
+  vec[1..3].copy_from_slice(&[11, 12]);
+  for i in &mut vec[3..] {
+    *i += 10;
+  }
+  assert_eq!(vec, [10, 11, 12, 13, 14]);
+  vec[..=1].reverse();
+  assert_eq!(vec, [11, 10, 12, 13, 14]);
+  assert_eq!(&mut vec[2..2], &mut []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![1, 2, 3][3] = 4);
+    assert_panics!(staticvec![1, 2, 3][2..4].reverse());
+  }
+}
+
+#[test]
+fn init_spare() {
+  let mut v = StaticVec::<usize, 6>::from([100]);
+  v.init_spare(3, |i| i * 10);
+  assert_eq!(v, [100, 10, 20, 30]);
+  v.init_spare(0, |_| panic!("should not be called for a count of 0"));
+  assert_eq!(v.len(), 4);
+  v.init_spare(2, |i| i);
+  assert_eq!(v, [100, 10, 20, 30, 4, 5]);
+  assert!(v.is_full());
+  let mut strings = StaticVec::<String, 3>::new();
+  strings.init_spare(2, |i| i.to_string());
+  assert_eq!(strings, ["0", "1"]);
+  #[cfg(feature = "std")]
+  {
+    let c = LifespanCounter::default();
+    let mut v2 = StaticVec::<LifespanCountingInstance, 4>::new();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+      v2.init_spare(3, |i| {
+        if i == 2 {
+          panic!("Initializer correctly panicked during a test");
+        }
+        c.instance()
+      });
+    }));
+    assert!(result.is_err());
+    assert_eq!(v2.len(), 2);
+    drop(v2);
+    assert_eq!(c.init_count(), 2);
+    assert_eq!(c.drop_count(), 2);
+  }
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v3 = StaticVec::<i32, 2>::from([1]);
+      v3.init_spare(2, |_| 0);
+    });
   }
 }
 
@@ -440,6 +1829,67 @@ fn insert() {
   assert_eq!(vec, [1, 4, 2, 3, 5]);
 }
 
+#[test]
+fn insert_from_slice() {
+  let mut vec = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  vec.insert_from_slice(1, &[4, 5, 6]);
+  assert_eq!(vec, [1, 4, 5, 6, 2, 3]);
+  vec.insert_from_slice(6, &[7]);
+  assert_eq!(vec, [1, 4, 5, 6, 2, 3, 7]);
+  vec.insert_from_slice(0, &[0]);
+  assert_eq!(vec, [0, 1, 4, 5, 6, 2, 3, 7]);
+  vec.insert_from_slice(3, &[]);
+  assert_eq!(vec, [0, 1, 4, 5, 6, 2, 3, 7]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(vec.clone().insert_from_slice(0, &[1]));
+    assert_panics!(StaticVec::<i32, 4>::new_from_slice(&[1]).insert_from_slice(2, &[1]));
+  }
+}
+
+#[test]
+fn insert_many() {
+  let mut v = StaticVec::<String, 8>::new();
+  v.push(String::from("a"));
+  v.push(String::from("e"));
+  v.insert_many(1, ["b", "c", "d"].iter().map(|s| s.to_string()));
+  assert_eq!(v, ["a", "b", "c", "d", "e"]);
+  v.insert_many(5, vec![String::from("f")]);
+  v.insert_many(0, core::iter::empty());
+  assert_eq!(v, ["a", "b", "c", "d", "e", "f"]);
+  let lifespan_tracker = LifespanCounter::default();
+  let mut v2 = StaticVec::<LifespanCountingInstance, 4>::new();
+  v2.insert_many(0, (0..3).map(|_| lifespan_tracker.instance()));
+  v2.insert_many(1, (0..1).map(|_| lifespan_tracker.instance()));
+  assert_eq!(v2.len(), 4);
+  drop(v2);
+  assert_eq!(lifespan_tracker.init_count(), 4);
+  assert_eq!(lifespan_tracker.drop_count(), 4);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![1, 2, 3].insert_many(4, Some(4)));
+    let mut v3 = StaticVec::<i32, 4>::new_from_slice(&[1, 2]);
+    assert_panics!(v3.clone().insert_many(1, 5..8));
+    v3.insert_many(1, 5..7);
+    assert_eq!(v3, [1, 5, 6, 2]);
+  }
+}
+
+#[test]
+fn intersection() {
+  let a = staticvec![1, 3, 5, 7, 9];
+  let overlapping: StaticVec<i32, 5> = a.intersection(&staticvec![3, 4, 5, 10]);
+  assert_eq!(overlapping, [3, 5]);
+  let disjoint: StaticVec<i32, 5> = a.intersection(&staticvec![2, 4, 6]);
+  assert_eq!(disjoint, []);
+  let subset: StaticVec<i32, 3> = a.intersection(&staticvec![1, 5, 9]);
+  assert_eq!(subset, [1, 5, 9]);
+  let empty: StaticVec<i32, 1> = a.intersection(&StaticVec::<i32, 4>::new());
+  assert_eq!(empty, []);
+}
+
 #[test]
 fn is_empty() {
   let mut v = StaticVec::<i32, 1>::new();
@@ -481,6 +1931,42 @@ fn iter() {
   assert_eq!("[3]", format!("{:?}", i));
   assert_eq!(*i.next().unwrap(), 3);
   assert_eq!("[]", format!("{:?}", i));
+  assert_eq!(i.next(), None);
+  assert_eq!(i.next_back(), None);
+  let reversed: StaticVec<i32, 5> = v.iter().rev().copied().collect();
+  assert_eq!(reversed, [5, 4, 3, 2, 1]);
+  let strings = staticvec![String::from("a"), String::from("b")];
+  assert_eq!(strings.iter().rev().next().unwrap(), "b");
+  // Zero-sized types are tracked with byte-offset pointers, so make sure that works backwards too.
+  let zsts = staticvec![(), (), (), ()];
+  assert_eq!(zsts.iter().rev().count(), 4);
+  let mut zi = zsts.iter();
+  assert_eq!(zi.next_back(), Some(&()));
+  assert_eq!(zi.next(), Some(&()));
+  assert_eq!(zi.len(), 2);
+  assert_eq!(zi.next_back(), Some(&()));
+  assert_eq!(zi.next_back(), Some(&()));
+  assert_eq!(zi.next_back(), None);
+  assert_eq!(zi.next(), None);
+  // Alternating between the two ends should always meet in the middle exactly once.
+  for length in 0..6 {
+    let v2 = StaticVec::<usize, 6>::filled_with_by_index(|i| i);
+    let v2 = &v2[..length];
+    let mut seen = StaticVec::<usize, 6>::new();
+    let mut it = v2.iter();
+    loop {
+      match it.next() {
+        Some(&x) => seen.push(x),
+        None => break,
+      }
+      match it.next_back() {
+        Some(&x) => seen.push(x),
+        None => break,
+      }
+    }
+    seen.sort_unstable();
+    assert_eq!(seen, v2);
+  }
 }
 
 #[test]
@@ -495,6 +1981,101 @@ fn iter_mut() {
   assert_eq!("[3]", format!("{:?}", i));
   assert_eq!(*i.next().unwrap(), 3);
   assert_eq!("[]", format!("{:?}", i));
+  assert_eq!(i.next_back(), None);
+  for (n, x) in v.iter_mut().rev().enumerate() {
+    *x *= n as i32;
+  }
+  assert_eq!(v, [4, 6, 6, 4, 0]);
+  let mut zsts = staticvec![(), (), ()];
+  let mut zi = zsts.iter_mut();
+  assert!(zi.next_back().is_some());
+  assert!(zi.next().is_some());
+  assert!(zi.next_back().is_some());
+  assert!(zi.next_back().is_none());
+  assert!(zi.next().is_none());
+  assert_eq!(zsts.iter_mut().rev().count(), 3);
+}
+
+#[test]
+fn iter_nth_and_last() {
+  let v = staticvec![1, 2, 3, 4, 5, 6, 7, 8];
+  let mut i = v.iter();
+  assert_eq!(i.nth(0), Some(&1));
+  assert_eq!(i.nth(2), Some(&4));
+  assert_eq!(i.len(), 4);
+  assert_eq!(i.clone().last(), Some(&8));
+  assert_eq!(i.nth(4), None);
+  assert_eq!(i.len(), 0);
+  assert_eq!(i.next(), None);
+  assert_eq!(i.next_back(), None);
+  let stepped: StaticVec<i32, 3> = v.iter().step_by(3).copied().collect();
+  assert_eq!(stepped, [1, 4, 7]);
+  assert_eq!(StaticVec::<i32, 4>::new().iter().last(), None);
+  let mut v2 = v.clone();
+  let mut im = v2.iter_mut();
+  *im.nth(1).unwrap() = 20;
+  assert_eq!(im.len(), 6);
+  assert!(im.nth(6).is_none());
+  assert!(im.next().is_none());
+  *v2.iter_mut().last().unwrap() = 80;
+  assert_eq!(v2, [1, 20, 3, 4, 5, 6, 7, 80]);
+  let zsts = staticvec![(), (), (), ()];
+  let mut zi = zsts.iter();
+  assert_eq!(zi.nth(2), Some(&()));
+  assert_eq!(zi.len(), 1);
+  assert_eq!(zi.nth(1), None);
+  assert_eq!(zi.len(), 0);
+  let mut zsts_mut = zsts.clone();
+  let mut zim = zsts_mut.iter_mut();
+  assert!(zim.nth(3).is_some());
+  assert!(zim.next().is_none());
+}
+
+#[test]
+fn into_array() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let a: [Box<i32>; 3] = v.into_array().unwrap();
+  assert_eq!(a, [Box::new(1), Box::new(2), Box::new(3)]);
+  let v2 = StaticVec::<i32, 4>::new_from_slice(&[1, 2, 3]);
+  let e = v2.into_array().unwrap_err();
+  assert_eq!(e, [1, 2, 3]);
+  assert_eq!(e.capacity(), 4);
+  let v3 = StaticVec::new_from_array([4, 5, 6, 7]);
+  assert_eq!(v3.into_array(), Ok([4, 5, 6, 7]));
+  let c = LifespanCounter::default();
+  let v4 = staticvec![c.instance(), c.instance()];
+  let a4 = v4.into_array().unwrap();
+  assert_eq!(c.drop_count(), 0);
+  drop(a4);
+  assert_eq!(c.init_count(), 2);
+  assert_eq!(c.drop_count(), 2);
+}
+
+#[test]
+fn into_capacity() {
+  let v = staticvec![Box::new(1), Box::new(2), Box::new(3)];
+  let v2: StaticVec<Box<i32>, 8> = v.into_capacity();
+  assert_eq!(v2, [Box::new(1), Box::new(2), Box::new(3)]);
+  assert_eq!(v2.capacity(), 8);
+  let v3: StaticVec<Box<i32>, 3> = v2.into_capacity();
+  assert_eq!(v3, [Box::new(1), Box::new(2), Box::new(3)]);
+  let v4: StaticVec<Box<i32>, 0> = StaticVec::<Box<i32>, 4>::new().into_capacity();
+  assert!(v4.is_empty());
+  let c = LifespanCounter::default();
+  {
+    let v5 = staticvec![c.instance(), c.instance()];
+    let _v6: StaticVec<_, 4> = v5.into_capacity();
+    assert_eq!(c.init_count(), 2);
+    assert_eq!(c.drop_count(), 0);
+  }
+  assert_eq!(c.drop_count(), 2);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<i32, 2> = staticvec![1, 2, 3].into_capacity();
+    });
+  }
 }
 
 #[test]
@@ -511,6 +2092,45 @@ fn into_iter() {
   assert_eq!("[]", format!("{:?}", i));
 }
 
+#[test]
+fn into_mapped() {
+  let v = staticvec![1, 2, 3, 4];
+  let m = v.into_mapped(|x| x.to_string());
+  assert_eq!(m.len(), 4);
+  assert_eq!(m.capacity(), 4);
+  assert_eq!(m, ["1", "2", "3", "4"]);
+  let c = LifespanCounter::default();
+  let v = staticvec![c.instance(), c.instance(), c.instance()];
+  assert_eq!(c.init_count(), 3);
+  let m = v.into_mapped(|_| 1u8);
+  assert_eq!(c.drop_count(), 3);
+  assert_eq!(m, [1, 1, 1]);
+  let e = StaticVec::<i32, 0>::new();
+  assert_eq!(e.into_mapped(|x| x * 2), []);
+}
+
+#[test]
+fn into_iter_drop() {
+  let lifespan_tracker = LifespanCounter::default();
+  let mut v = StaticVec::<LifespanCountingInstance, 8>::new();
+  for _ in 0..6 {
+    v.push(lifespan_tracker.instance());
+  }
+  let mut i = v.into_iter();
+  // Yielded values are owned by the caller, and are dropped as soon as they go out of scope.
+  drop(i.next().unwrap());
+  drop(i.next_back().unwrap());
+  assert_eq!(lifespan_tracker.drop_count(), 2);
+  // The remaining four values must be dropped exactly once by the iterator itself.
+  drop(i);
+  assert_eq!(lifespan_tracker.init_count(), 6);
+  assert_eq!(lifespan_tracker.drop_count(), 6);
+  let mut v2 = StaticVec::<LifespanCountingInstance, 4>::new();
+  v2.push(lifespan_tracker.instance());
+  for _ in v2 {}
+  assert_eq!(lifespan_tracker.drop_count(), 7);
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn into_vec() {
@@ -531,6 +2151,47 @@ fn into_vec() {
   assert_eq!(v.len(), 3);
 }
 
+#[test]
+fn join() {
+  let v = staticvec![
+    staticvec![1, 2],
+    StaticVec::new_from_slice(&[3]),
+    StaticVec::<i32, 2>::new(),
+    StaticVec::new_from_slice(&[4])
+  ];
+  let j: StaticVec<i32, 10> = v.join(&[0]);
+  assert_eq!(j, [1, 2, 0, 3, 0, 0, 4]);
+  let j2: StaticVec<i32, 7> = v.join(&[]);
+  assert_eq!(j2, [1, 2, 3, 4]);
+  let words = staticvec!["ab".as_bytes(), "c".as_bytes()];
+  let j3: StaticVec<u8, 5> = words.join(b", ");
+  assert_eq!(j3, *b"ab, c");
+  let single = staticvec![[1, 2]];
+  let j4: StaticVec<i32, 2> = single.join(&[9, 9, 9]);
+  assert_eq!(j4, [1, 2]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<i32, 6> = v.join(&[0]);
+    });
+  }
+}
+
+#[test]
+fn keep_last() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  v.keep_last(10);
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+  v.keep_last(2);
+  assert_eq!(v, [4, 5]);
+  v.keep_last(0);
+  assert_eq!(v, []);
+  let mut v2 = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  v2.keep_last(1);
+  assert_eq!(v2, ["c"]);
+}
+
 #[test]
 fn last() {
   let v = staticvec![1, 2, 3];
@@ -543,6 +2204,27 @@ fn last_mut() {
   assert_eq!(*v.last_mut().unwrap(), 3);
 }
 
+#[test]
+fn last_chunk() {
+  let v = StaticVec::<u8, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  let footer: Option<&[u8; 2]> = v.last_chunk();
+  assert_eq!(footer, Some(&[4, 5]));
+  let all: Option<&[u8; 5]> = v.last_chunk();
+  assert_eq!(all, Some(&[1, 2, 3, 4, 5]));
+  let too_long: Option<&[u8; 6]> = v.last_chunk();
+  assert_eq!(too_long, None);
+}
+
+#[test]
+fn last_chunk_mut() {
+  let mut v = StaticVec::<u8, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  let footer: Option<&mut [u8; 3]> = v.last_chunk_mut();
+  footer.unwrap().reverse();
+  assert_eq!(v, [1, 2, 5, 4, 3]);
+  let too_long: Option<&mut [u8; 6]> = v.last_chunk_mut();
+  assert!(too_long.is_none());
+}
+
 #[test]
 fn len() {
   let a = staticvec![1, 2, 3];
@@ -555,6 +2237,85 @@ fn macros() {
   let _v = staticvec![staticvec![staticvec![1, 2, 3, 4]]];
   // The type of the StaticVec on the next line is `StaticVec<f32, 64>`.
   let _v2 = staticvec![12.0; 64];
+  let v3 = staticvec![1, 2, 3,];
+  assert_eq!(v3, [1, 2, 3]);
+  let v4: StaticVec<i32, 4> = staticvec![];
+  assert_eq!(v4.len(), 0);
+  assert_eq!(v4.capacity(), 4);
+  let v5 = staticvec![String::from("A"); 3];
+  assert_eq!(v5, ["A", "A", "A"]);
+}
+
+#[test]
+fn mapped() {
+  let v = staticvec![1, 2, 3, 4];
+  let m = v.mapped(|x| x.to_string());
+  assert_eq!(m.len(), v.len());
+  assert_eq!(m.capacity(), 4);
+  assert_eq!(m, ["1", "2", "3", "4"]);
+  let mut v2 = StaticVec::<i32, 8>::new();
+  v2.push(5);
+  let m2 = v2.mapped(|&x| x * 2);
+  assert_eq!(m2.len(), 1);
+  assert_eq!(m2.capacity(), 8);
+  assert_eq!(m2, [10]);
+}
+
+#[test]
+fn max_by() {
+  let v = staticvec![(1, 'a'), (3, 'b'), (3, 'c'), (2, 'd')];
+  assert_eq!(v.max_by(|a, b| a.0.cmp(&b.0)), Some(&(3, 'c')));
+  assert_eq!(v.max_by(|a, b| b.0.cmp(&a.0)), Some(&(1, 'a')));
+  assert_eq!(
+    StaticVec::<(i32, char), 4>::new().max_by(|a, b| a.cmp(b)),
+    None
+  );
+}
+
+#[test]
+fn max_by_key() {
+  let v = staticvec![-3i32, 2, 3, -1];
+  assert_eq!(v.max_by_key(|x| x.abs()), Some(&3));
+  assert_eq!(v.max_by_key(|&x| x), Some(&3));
+  assert_eq!(StaticVec::<i32, 4>::new().max_by_key(|&x| x), None);
+}
+
+#[test]
+fn max_element() {
+  let v = staticvec![3, 9, 1, 9, 4];
+  assert_eq!(v.max_element(), Some(&9));
+  // Ties resolve to the last maximum, as with `Iterator::max`.
+  let v2 = staticvec![1, 9, 9];
+  assert!(core::ptr::eq(v2.max_element().unwrap(), &v2[2]));
+  assert_eq!(StaticVec::<i32, 4>::new().max_element(), None);
+}
+
+#[test]
+fn min_by() {
+  let v = staticvec![(2, 'a'), (1, 'b'), (1, 'c'), (3, 'd')];
+  assert_eq!(v.min_by(|a, b| a.0.cmp(&b.0)), Some(&(1, 'b')));
+  assert_eq!(v.min_by(|a, b| b.0.cmp(&a.0)), Some(&(3, 'd')));
+  assert_eq!(
+    StaticVec::<(i32, char), 4>::new().min_by(|a, b| a.cmp(b)),
+    None
+  );
+}
+
+#[test]
+fn min_by_key() {
+  let v = staticvec![-3i32, 2, -1, 1];
+  assert_eq!(v.min_by_key(|x| x.abs()), Some(&-1));
+  assert_eq!(v.min_by_key(|&x| x), Some(&-3));
+  assert_eq!(StaticVec::<i32, 4>::new().min_by_key(|&x| x), None);
+}
+
+#[test]
+fn min_element() {
+  let v = staticvec![3, 1, 9, 1, 4];
+  assert_eq!(v.min_element(), Some(&1));
+  // Ties resolve to the first minimum, as with `Iterator::min`.
+  assert!(core::ptr::eq(v.min_element().unwrap(), &v[1]));
+  assert_eq!(StaticVec::<i32, 4>::new().min_element(), None);
 }
 
 #[test]
@@ -605,6 +2366,33 @@ fn new_from_array() {
   assert_eq!(v2, [1, 2, 3]);
 }
 
+#[test]
+fn ord() {
+  let prefix = StaticVec::<i32, 3>::new_from_slice(&[1, 2]);
+  let extended = staticvec![1, 2, 3];
+  assert_eq!(prefix.cmp(&extended), Ordering::Less);
+  assert_eq!(extended.cmp(&prefix), Ordering::Greater);
+  assert_eq!(extended.cmp(&extended.clone()), Ordering::Equal);
+  assert_eq!(staticvec![1, 3, 0].cmp(&extended), Ordering::Greater);
+  // Consistency with `PartialEq`.
+  let a = staticvec![String::from("a"), String::from("b")];
+  let b = a.clone();
+  assert!(a == b && a.cmp(&b) == Ordering::Equal);
+  // Nested StaticVecs can be sorted, and used in ordered collections.
+  let mut nested = staticvec![staticvec![2, 0], staticvec![1, 5], staticvec![1, 2]];
+  nested.sort_unstable();
+  assert_eq!(
+    nested,
+    [staticvec![1, 2], staticvec![1, 5], staticvec![2, 0]]
+  );
+  let set: std::collections::BTreeSet<StaticVec<u8, 3>> = [b"ba", b"ab", b"ba"]
+    .iter()
+    .map(|s| StaticVec::new_from_slice(&s[..]))
+    .collect();
+  let ordered: StaticVec<&StaticVec<u8, 3>, 2> = set.iter().collect();
+  assert_eq!(ordered, [b"ab", b"ba"]);
+}
+
 #[test]
 fn partial_eq() {
   assert_eq!(StaticVec::<i32, 0>::new(), [0; 0]);
@@ -640,16 +2428,77 @@ fn partial_eq() {
   if staticvec![1; 64] != &mut staticvec![1; 64] {
     panic!();
   }
+  // Only the inhabited contents matter, not the capacity.
+  let v = StaticVec::<i32, 4>::new_from_slice(&[1, 2, 3]);
+  let v2 = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  assert_eq!(v, v2);
+  assert_eq!(v2, v);
+  assert_eq!(&v, v2);
+  assert_ne!(v, StaticVec::<i32, 4>::new_from_slice(&[1, 2, 4]));
+  assert_ne!(v, StaticVec::<i32, 8>::new_from_slice(&[1, 2]));
+  assert_ne!(v, StaticVec::<i32, 3>::new());
+  assert_eq!(StaticVec::<i32, 4>::new(), StaticVec::<i32, 0>::new());
+  // The element types don't need to match either, so long as they're comparable.
+  let strings = staticvec![String::from("a"), String::from("b")];
+  let strs = StaticVec::<&str, 6>::new_from_slice(&["a", "b"]);
+  assert!(strings == strs);
+  assert!(strings != StaticVec::<&str, 2>::new_from_slice(&["a", "c"]));
 }
 
 #[test]
 fn partial_ord() {
-  // TODO: add more here.
   assert!(staticvec![1] < staticvec![2]);
   assert!(staticvec![1] > []);
   assert!(staticvec![1] <= &staticvec![2]);
   assert!(staticvec![1] >= &[]);
   assert!(staticvec![1] > &mut []);
+  // Comparisons are lexicographic over the inhabited area, regardless of capacity.
+  let prefix = StaticVec::<i32, 4>::new_from_slice(&[1, 2]);
+  let extended = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  assert!(prefix < extended);
+  assert!(extended > prefix);
+  assert_eq!(prefix.partial_cmp(&extended), Some(Ordering::Less));
+  assert_eq!(
+    prefix.partial_cmp(&StaticVec::<i32, 2>::new_from_slice(&[1, 2])),
+    Some(Ordering::Equal)
+  );
+  assert!(staticvec![1, 3] > extended);
+  assert!(staticvec![1, 2, 3] <= [1, 2, 3]);
+  assert!(staticvec![1, 2, 3] >= [1, 2, 3]);
+  assert!(staticvec![0, 9, 9] < [1]);
+  assert_eq!(
+    staticvec![1.0, std::f64::NAN].partial_cmp(&staticvec![1.0, 2.0]),
+    None
+  );
+}
+
+#[test]
+fn pairs() {
+  let v = staticvec![1, 2, 3];
+  let mut it = v.pairs();
+  assert_eq!(it.next(), Some((&1, &2)));
+  assert_eq!(it.next(), Some((&2, &3)));
+  assert_eq!(it.next(), None);
+  let deltas: StaticVec<i32, 2> = v.pairs().map(|(prev, curr)| curr - prev).collect();
+  assert_eq!(deltas, [1, 1]);
+  assert_eq!(staticvec![1].pairs().next(), None);
+  assert_eq!(StaticVec::<i32, 4>::new().pairs().count(), 0);
+}
+
+#[test]
+fn partition_point() {
+  let v = staticvec![1, 2, 3, 3, 5, 6, 7];
+  assert_eq!(v.partition_point(|&x| x < 5), 4);
+  assert_eq!(v.partition_point(|&x| x <= 3), 4);
+  assert_eq!(v.partition_point(|&x| x < 2), 1);
+  assert_eq!(v.partition_point(|_| true), v.len());
+  assert_eq!(v.partition_point(|_| false), 0);
+  assert_eq!(StaticVec::<i32, 4>::new().partition_point(|_| true), 0);
+  // Finding the insertion point that keeps the StaticVec sorted.
+  let mut v2 = StaticVec::<i32, 8>::new_from_slice(&[10, 20, 30]);
+  let idx = v2.partition_point(|&x| x < 25);
+  v2.insert(idx, 25);
+  assert_eq!(v2, [10, 20, 25, 30]);
 }
 
 #[test]
@@ -659,6 +2508,58 @@ fn pop() {
   assert_eq!(vec, [1, 2]);
 }
 
+#[test]
+fn pop_front() {
+  let mut vec = staticvec![1, 2, 3];
+  assert_eq!(vec.pop_front(), Some(1));
+  assert_eq!(vec, [2, 3]);
+  assert_eq!(vec.pop_front(), Some(2));
+  assert_eq!(vec.pop_front(), Some(3));
+  assert_eq!(vec.pop_front(), None);
+  let mut q = StaticVec::<i32, 4>::new();
+  q.push(1);
+  q.push(2);
+  assert_eq!(q.pop_front(), Some(1));
+  q.push(3);
+  q.push(4);
+  assert_eq!(q.pop_front(), Some(2));
+  assert_eq!(q.pop(), Some(4));
+  assert_eq!(q.pop_front(), Some(3));
+  assert!(q.is_empty());
+}
+
+#[test]
+fn pop_n() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  let popped: StaticVec<i32, 2> = v.pop_n();
+  assert_eq!(popped, [4, 5]);
+  assert_eq!(v, [1, 2, 3]);
+  let none: StaticVec<i32, 0> = v.pop_n();
+  assert_eq!(none, []);
+  assert_eq!(v, [1, 2, 3]);
+  let all: StaticVec<i32, 8> = v.pop_n();
+  assert_eq!(all, [1, 2, 3]);
+  assert_eq!(all.capacity(), 8);
+  assert_eq!(v, []);
+  let c = LifespanCounter::default();
+  let mut v2 = staticvec![c.instance(), c.instance(), c.instance()];
+  let p2: StaticVec<_, 2> = v2.pop_n();
+  drop(v2);
+  assert_eq!(c.drop_count(), 1);
+  drop(p2);
+  assert_eq!(c.init_count(), 3);
+  assert_eq!(c.drop_count(), 3);
+}
+
+#[test]
+fn product() {
+  let v = staticvec![1, 2, 3, 4];
+  assert_eq!(v.product::<i32>(), 24);
+  let f = staticvec![0.5f64, 4.0];
+  assert_eq!(f.product::<f64>(), 2.0);
+  assert_eq!(StaticVec::<i32, 4>::new().product::<i32>(), 1);
+}
+
 #[test]
 fn ptr_at() {
   let v = staticvec![1, 2, 3];
@@ -682,6 +2583,68 @@ fn push() {
   assert_eq!(vec, [1, 2, 3, 3]);
 }
 
+#[test]
+fn push_front() {
+  let mut vec = StaticVec::<i32, 4>::new_from_slice(&[1, 2]);
+  vec.push_front(0);
+  vec.push(3);
+  assert_eq!(vec, [0, 1, 2, 3]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![1, 2].push_front(0));
+  }
+}
+
+#[test]
+fn push_ints() {
+  let mut v = StaticVec::<u8, 64>::new();
+  v.push_u16_le(0x1234).unwrap();
+  v.push_u16_be(0x1234).unwrap();
+  assert_eq!(v, [0x34, 0x12, 0x12, 0x34]);
+  v.push_i16_le(-2).unwrap();
+  v.push_i16_be(-2).unwrap();
+  v.push_u32_le(0xDEAD_BEEF).unwrap();
+  v.push_u32_be(0xDEAD_BEEF).unwrap();
+  v.push_i32_le(i32::min_value()).unwrap();
+  v.push_i32_be(-123_456).unwrap();
+  v.push_u64_le(0x0123_4567_89AB_CDEF).unwrap();
+  v.push_u64_be(u64::max_value()).unwrap();
+  v.push_i64_le(-1).unwrap();
+  v.push_i64_be(i64::min_value() + 1).unwrap();
+  assert_eq!(v.len(), 4 + 4 + 16 + 32);
+  let mut a2 = [0; 2];
+  let mut a4 = [0; 4];
+  let mut a8 = [0; 8];
+  a2.copy_from_slice(&v[4..6]);
+  assert_eq!(i16::from_le_bytes(a2), -2);
+  a2.copy_from_slice(&v[6..8]);
+  assert_eq!(i16::from_be_bytes(a2), -2);
+  a4.copy_from_slice(&v[8..12]);
+  assert_eq!(u32::from_le_bytes(a4), 0xDEAD_BEEF);
+  a4.copy_from_slice(&v[12..16]);
+  assert_eq!(u32::from_be_bytes(a4), 0xDEAD_BEEF);
+  a4.copy_from_slice(&v[16..20]);
+  assert_eq!(i32::from_le_bytes(a4), i32::min_value());
+  a4.copy_from_slice(&v[20..24]);
+  assert_eq!(i32::from_be_bytes(a4), -123_456);
+  a8.copy_from_slice(&v[24..32]);
+  assert_eq!(u64::from_le_bytes(a8), 0x0123_4567_89AB_CDEF);
+  a8.copy_from_slice(&v[32..40]);
+  assert_eq!(u64::from_be_bytes(a8), u64::max_value());
+  a8.copy_from_slice(&v[40..48]);
+  assert_eq!(i64::from_le_bytes(a8), -1);
+  a8.copy_from_slice(&v[48..56]);
+  assert_eq!(i64::from_be_bytes(a8), i64::min_value() + 1);
+  let mut small = StaticVec::<u8, 3>::new();
+  assert_eq!(small.push_u16_be(1), Ok(()));
+  assert_eq!(
+    small.push_u16_be(1),
+    Err("Insufficient remaining capacity!")
+  );
+  assert_eq!(small, [0, 1]);
+}
+
 #[cfg(feature = "std")]
 mod read_tests {
   use staticvec::*;
@@ -763,6 +2726,106 @@ mod read_tests {
   }
 }
 
+#[test]
+fn rchunks() {
+  let v = staticvec![1, 2, 3, 4, 5];
+  let mut it = v.rchunks(2);
+  assert_eq!(it.next(), Some(&[4, 5][..]));
+  assert_eq!(it.next(), Some(&[2, 3][..]));
+  assert_eq!(it.next(), Some(&[1][..]));
+  assert_eq!(it.next(), None);
+  assert_eq!(StaticVec::<i32, 4>::new().rchunks(3).next(), None);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      staticvec![1, 2, 3].rchunks(0);
+    });
+  }
+}
+
+#[test]
+fn rchunks_mut() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  for (i, chunk) in v.rchunks_mut(2).enumerate() {
+    for x in chunk {
+      *x += i as i32 * 10;
+    }
+  }
+  assert_eq!(v, [21, 12, 13, 4, 5]);
+}
+
+#[test]
+fn reader() {
+  let mut v = StaticVec::<u8, 32>::new();
+  v.push(7);
+  v.push_u16_be(0xCAFE).unwrap();
+  v.push_u32_le(0xDEAD_BEEF).unwrap();
+  v.extend_from_slice(b"abc");
+  let mut r = v.reader();
+  assert_eq!(r.remaining(), 10);
+  assert_eq!(r.read_u8(), Some(7));
+  assert_eq!(r.read_u16_be(), Some(0xCAFE));
+  assert_eq!(r.position(), 3);
+  assert_eq!(r.read_u32_le(), Some(0xDEAD_BEEF));
+  assert_eq!(r.remaining_slice(), b"abc");
+  assert_eq!(r.read_u32_be(), None);
+  assert_eq!(r.position(), 7);
+  assert_eq!(r.read_slice(3), Some(&b"abc"[..]));
+  assert!(r.is_empty());
+  assert_eq!(r.read_u8(), None);
+  assert_eq!(r.read_slice(1), None);
+  assert_eq!(r.read_slice(0), Some(&[][..]));
+  let empty = StaticVec::<u8, 4>::new();
+  let mut r2 = empty.reader();
+  assert!(r2.is_empty());
+  assert_eq!(r2.read_u16_le(), None);
+  assert_eq!(r2.read_u64_be(), None);
+}
+
+#[test]
+fn reinterpret_as() {
+  let v = staticvec![[1u8, 0, 0, 0], [0, 1, 0, 0], [0xFF, 0xFF, 0xFF, 0xFF]];
+  let aligned = v.as_ptr() as usize % core::mem::align_of::<u32>() == 0;
+  match v.reinterpret_as::<u32>() {
+    Ok(words) => {
+      assert!(aligned);
+      assert_eq!(
+        words,
+        [
+          u32::from_ne_bytes([1, 0, 0, 0]),
+          u32::from_ne_bytes([0, 1, 0, 0]),
+          0xFFFF_FFFF
+        ]
+      );
+    }
+    Err(e) => {
+      assert!(!aligned);
+      assert_eq!(e, "Data must be aligned for `U`!");
+    }
+  }
+  // Reinterpreting as a type with the same or a smaller alignment can't fail on alignment.
+  assert_eq!(v.reinterpret_as::<u8>().unwrap().len(), 12);
+  assert_eq!(
+    v.reinterpret_as::<[u8; 6]>().unwrap(),
+    [[1, 0, 0, 0, 0, 1], [0, 0, 0xFF, 0xFF, 0xFF, 0xFF]]
+  );
+  assert_eq!(
+    v.reinterpret_as::<[u8; 8]>(),
+    Err("Length in bytes must be a multiple of the size of `U`!")
+  );
+  let shorts = staticvec![1u16, 2, 3];
+  assert_eq!(
+    shorts.reinterpret_as::<u32>(),
+    Err("Length in bytes must be a multiple of the size of `U`!")
+  );
+  assert_eq!(
+    StaticVec::<u8, 4>::new().reinterpret_as::<u64>(),
+    Ok(&[][..])
+  );
+  assert!(shorts.reinterpret_as::<[u8; 0]>().is_err());
+}
+
 #[test]
 fn remaining_capacity() {
   let mut v = StaticVec::<i32, 3>::new();
@@ -778,27 +2841,381 @@ fn remove() {
 }
 
 #[test]
-fn remove_item() {
-  let mut vec = staticvec![1, 2, 3, 1];
-  vec.remove_item(&1);
-  assert_eq!(vec, staticvec![2, 3, 1]);
+fn remove_all() {
+  let mut v = staticvec![3, 8, 1, 6, 6, 9, 2];
+  let removed = v.remove_all(|&x| x % 2 == 0);
+  assert_eq!(removed, [8, 6, 6, 2]);
+  assert_eq!(v, [3, 1, 9]);
+  assert_eq!(v.remove_all(|&x| x > 100), []);
+  assert_eq!(v, [3, 1, 9]);
 }
 
 #[test]
-fn retain() {
-  let mut vec = staticvec![1, 2, 3, 4, 5];
-  let keep = [false, true, true, false, true];
-  let mut i = 0;
-  vec.retain(|_| (keep[i], i += 1).0);
+fn remove_range() {
+  let mut v = staticvec![1, 2, 3, 4, 5, 6, 7];
+  v.remove_range(2..4);
+  assert_eq!(v, [1, 2, 5, 6, 7]);
+  v.remove_range(3..);
+  assert_eq!(v, [1, 2, 5]);
+  v.remove_range(1..1);
+  assert_eq!(v, [1, 2, 5]);
+  v.remove_range(..=0);
+  assert_eq!(v, [2, 5]);
+  v.remove_range(..);
+  assert_eq!(v, []);
+  let c = LifespanCounter::default();
+  {
+    let mut v2: StaticVec<LifespanCountingInstance, 8> = (0..6).map(|_| c.instance()).collect();
+    v2.remove_range(1..4);
+    assert_eq!(v2.len(), 3);
+    assert_eq!(c.drop_count(), 3);
+    v2.remove_range(2..);
+    assert_eq!(v2.len(), 2);
+    assert_eq!(c.drop_count(), 4);
+  }
+  assert_eq!(c.init_count(), 6);
+  assert_eq!(c.drop_count(), 6);
+  let mut strings = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  strings.remove_range(..2);
+  assert_eq!(strings, ["c"]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v3 = staticvec![1, 2, 3];
+      v3.remove_range(2..4);
+    });
+    assert_panics!({
+      let mut v3 = staticvec![1, 2, 3];
+      v3.remove_range(2..1);
+    });
+  }
+}
+
+#[test]
+fn remove_item() {
+  let mut vec = staticvec![1, 2, 3, 1];
+  vec.remove_item(&1);
+  assert_eq!(vec, staticvec![2, 3, 1]);
+}
+
+#[test]
+fn resize() {
+  let mut vec = StaticVec::<Box<i32>, 6>::new();
+  vec.resize(4, Box::new(7));
+  assert_eq!(vec, [Box::new(7), Box::new(7), Box::new(7), Box::new(7)]);
+  vec.resize(4, Box::new(8));
+  assert_eq!(vec.len(), 4);
+  vec.resize(1, Box::new(8));
+  assert_eq!(vec, [Box::new(7)]);
+  vec.resize(6, Box::new(9));
+  assert_eq!(vec.len(), 6);
+  assert_eq!(*vec[5], 9);
+  vec.resize(0, Box::new(9));
+  assert_eq!(vec, []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v = StaticVec::<i32, 2>::new();
+      v.resize(3, 0);
+    });
+  }
+}
+
+#[test]
+fn resize_with() {
+  let mut vec = staticvec![1, 2, 3, 0, 0];
+  vec.resize_with(2, Default::default);
+  assert_eq!(vec, [1, 2]);
+  let mut i = 2;
+  vec.resize_with(5, || {
+    i += 1;
+    i
+  });
+  assert_eq!(vec, [1, 2, 3, 4, 5]);
+  vec.resize_with(5, || 0);
+  assert_eq!(vec, [1, 2, 3, 4, 5]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v = StaticVec::<i32, 2>::new();
+      v.resize_with(3, Default::default);
+    });
+  }
+}
+
+#[test]
+fn resize_default() {
+  let mut vec = StaticVec::<i32, 8>::from([1, 2]);
+  vec.resize_default(6);
+  assert_eq!(vec, [1, 2, 0, 0, 0, 0]);
+  vec.resize_default(6);
+  assert_eq!(vec.len(), 6);
+  vec.resize_default(2);
+  assert_eq!(vec, [1, 2]);
+  vec.resize_default(0);
+  assert_eq!(vec, []);
+  let mut strings = StaticVec::<String, 3>::new();
+  strings.resize_default(3);
+  assert_eq!(strings, ["", "", ""]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v = StaticVec::<i32, 8>::new();
+      v.resize_default(9);
+    });
+  }
+}
+
+#[test]
+fn retain() {
+  let mut vec = staticvec![1, 2, 3, 4, 5];
+  let keep = [false, true, true, false, true];
+  let mut i = 0;
+  vec.retain(|_| (keep[i], i += 1).0);
   assert_eq!(vec, [2, 3, 5]);
 }
 
+#[test]
+fn retain_mut() {
+  // Decrement each "TTL" and drop the entries that hit zero, in a single pass.
+  let mut vec = staticvec![(1, 'a'), (3, 'b'), (1, 'c'), (2, 'd'), (4, 'e')];
+  vec.retain_mut(|(ttl, _)| {
+    *ttl -= 1;
+    *ttl > 0
+  });
+  assert_eq!(vec, [(2, 'b'), (1, 'd'), (3, 'e')]);
+  vec.retain_mut(|(ttl, _)| {
+    *ttl -= 1;
+    *ttl > 0
+  });
+  assert_eq!(vec, [(1, 'b'), (2, 'e')]);
+  let mut strings = staticvec![String::from("a"), String::from("bb"), String::from("c")];
+  strings.retain_mut(|s| {
+    s.push('!');
+    s.len() == 2
+  });
+  assert_eq!(strings, [String::from("a!"), String::from("c!")]);
+}
+
+#[test]
+fn retain_with_index() {
+  let mut v = staticvec![0, 1, 2, 3, 4, 5, 6, 7];
+  v.retain_with_index(|i, _| i % 2 == 0);
+  assert_eq!(v, [0, 2, 4, 6]);
+  let mut v2 = staticvec!["a", "b", "c", "d"];
+  let mut seen = StaticVec::<usize, 4>::new();
+  v2.retain_with_index(|i, &s| {
+    seen.push(i);
+    s != "a" && i != 2
+  });
+  assert_eq!(seen, [0, 1, 2, 3]);
+  assert_eq!(v2, ["b", "d"]);
+}
+
+#[test]
+fn reverse() {
+  let mut v = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  v.reverse();
+  assert_eq!(v, ["c", "b", "a"]);
+  let mut v2 = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4]);
+  v2.reverse();
+  assert_eq!(v2, [4, 3, 2, 1]);
+  assert_eq!(v2.len(), 4);
+  let mut v3 = staticvec![1];
+  v3.reverse();
+  assert_eq!(v3, [1]);
+  let mut v4 = StaticVec::<i32, 2>::new();
+  v4.reverse();
+  assert!(v4.is_empty());
+}
+
 #[test]
 fn reversed() {
   let v = staticvec![1, 2, 3].reversed();
   assert!(v == [3, 2, 1]);
 }
 
+#[test]
+fn rfind_byte() {
+  for length in [0usize, 1, 7, 8, 9, 15, 16, 17, 31, 32, 33].iter().cloned() {
+    let mut v = StaticVec::<u8, 40>::new();
+    for i in 0..length {
+      v.push(i as u8 + 1);
+    }
+    assert_eq!(v.rfind_byte(0), None);
+    assert_eq!(v.rfind_byte(100), None);
+    for i in 0..length {
+      assert_eq!(v.rfind_byte(i as u8 + 1), Some(i));
+    }
+  }
+  let v = StaticVec::<u8, 20>::new_from_slice(b"abc,defghijkl,mnopq");
+  assert_eq!(v.rfind_byte(b','), Some(13));
+  assert_eq!(v.rfind_byte(b'a'), Some(0));
+  assert_eq!(v.rfind_byte(b'q'), Some(18));
+  assert_eq!(v.rfind_byte(b'z'), None);
+  assert_eq!(staticvec![0x80u8, 0xFF, 0x80].rfind_byte(0x80), Some(2));
+}
+
+#[test]
+fn ring_buffer() {
+  let mut r = StaticRingBuffer::<i32, 4>::new();
+  assert!(r.is_empty());
+  assert_eq!(r.capacity(), 4);
+  assert_eq!(r.front(), None);
+  assert_eq!(r.back(), None);
+  for i in 1..=4 {
+    assert_eq!(r.push_back(i), None);
+  }
+  assert!(r.is_full());
+  assert_eq!(
+    r.iter().copied().collect::<StaticVec<i32, 4>>(),
+    [1, 2, 3, 4]
+  );
+  // Pushing past capacity overwrites the oldest elements.
+  assert_eq!(r.push_back(5), Some(1));
+  assert_eq!(r.push_back(6), Some(2));
+  assert_eq!(r.len(), 4);
+  assert_eq!(
+    r.iter().copied().collect::<StaticVec<i32, 4>>(),
+    [3, 4, 5, 6]
+  );
+  assert_eq!(r.as_slices(), (&[3, 4][..], &[5, 6][..]));
+  assert_eq!(r.front(), Some(&3));
+  assert_eq!(r.back(), Some(&6));
+  assert_eq!(r.get(2), Some(&5));
+  assert_eq!(r.get(4), None);
+  assert_eq!(r.pop_front(), Some(3));
+  assert_eq!(r.pop_front(), Some(4));
+  assert_eq!(r.len(), 2);
+  r.extend(7..10);
+  assert_eq!(format!("{:?}", r), "[6, 7, 8, 9]");
+  let mut total = 0;
+  for i in &r {
+    total += i;
+  }
+  assert_eq!(total, 30);
+  while r.pop_front().is_some() {}
+  assert!(r.is_empty());
+  assert_eq!(r.pop_front(), None);
+  r.push_back(10);
+  assert_eq!(r.iter().copied().collect::<StaticVec<i32, 4>>(), [10]);
+  let mut zero = StaticRingBuffer::<i32, 0>::new();
+  assert_eq!(zero.push_back(1), Some(1));
+  assert_eq!(zero.pop_front(), None);
+  let mut one = StaticRingBuffer::<i32, 1>::default();
+  assert_eq!(one.push_back(1), None);
+  assert_eq!(one.push_back(2), Some(1));
+  assert_eq!(one.front(), Some(&2));
+}
+
+#[test]
+fn ring_buffer_drop() {
+  let c = LifespanCounter::default();
+  {
+    let mut r = StaticRingBuffer::<LifespanCountingInstance, 3>::new();
+    for _ in 0..5 {
+      r.push_back(c.instance());
+    }
+    // The two overwritten elements were returned from `push_back` and dropped immediately.
+    assert_eq!(c.drop_count(), 2);
+    drop(r.pop_front());
+    assert_eq!(c.drop_count(), 3);
+    r.push_back(c.instance());
+    let r2 = r.clone();
+    assert_eq!(r2.len(), 3);
+    assert_eq!(c.init_count(), 9);
+    r.clear();
+    assert!(r.is_empty());
+    assert_eq!(c.drop_count(), 6);
+  }
+  assert_eq!(c.init_count(), 9);
+  assert_eq!(c.drop_count(), 9);
+}
+
+#[test]
+fn rotate_left() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  v.rotate_left(0);
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+  v.rotate_left(2);
+  assert_eq!(v, [3, 4, 5, 1, 2]);
+  v.rotate_left(5);
+  assert_eq!(v, [3, 4, 5, 1, 2]);
+  let mut v2 = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  v2.rotate_left(1);
+  assert_eq!(v2, [2, 3, 1]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![1, 2, 3].rotate_left(4));
+  }
+}
+
+#[test]
+fn rotate_right() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  v.rotate_right(0);
+  assert_eq!(v, [1, 2, 3, 4, 5]);
+  v.rotate_right(2);
+  assert_eq!(v, [4, 5, 1, 2, 3]);
+  v.rotate_right(5);
+  assert_eq!(v, [4, 5, 1, 2, 3]);
+  let mut v2 = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  v2.rotate_right(1);
+  assert_eq!(v2, [3, 1, 2]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![1, 2, 3].rotate_right(4));
+  }
+}
+
+#[test]
+fn rposition() {
+  let v = staticvec![1, 2, 3, 2, 1];
+  assert_eq!(v.rposition(|&x| x == 2), Some(3));
+  assert_eq!(v.rposition(|&x| x == 1), Some(4));
+  assert_eq!(v.rposition(|&x| x == 3), Some(2));
+  assert_eq!(v.rposition(|&x| x > 3), None);
+  assert_eq!(StaticVec::<i32, 4>::new().rposition(|_| true), None);
+}
+
+#[test]
+fn rsplit() {
+  let v = staticvec![1, 0, 2, 3, 0, 4];
+  let mut it = v.rsplit(|&x| x == 0);
+  assert_eq!(it.next(), Some(&[4][..]));
+  assert_eq!(it.next(), Some(&[2, 3][..]));
+  assert_eq!(it.next(), Some(&[1][..]));
+  assert_eq!(it.next(), None);
+}
+
+#[test]
+fn rsplitn() {
+  let v = staticvec![1, 0, 2, 3, 0, 4];
+  let parts: StaticVec<&[i32], 2> = v.rsplitn(2, |&x| x == 0).collect();
+  assert_eq!(parts, [&[4][..], &[1, 0, 2, 3][..]]);
+}
+
+#[test]
+fn saturating_extend_from_slice() {
+  let mut v = StaticVec::<i32, 6>::new();
+  assert_eq!(v.saturating_extend_from_slice(&[1, 2, 3]), 0);
+  assert_eq!(v, [1, 2, 3]);
+  assert_eq!(v.saturating_extend_from_slice(&[]), 0);
+  assert_eq!(v.saturating_extend_from_slice(&[4, 5, 6, 7, 8]), 2);
+  assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+  assert_eq!(v.saturating_extend_from_slice(&[9]), 1);
+  assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+  let mut v2 = StaticVec::<u8, 4>::new();
+  assert_eq!(v2.saturating_extend_from_slice(b"abcd"), 0);
+  assert!(v2.is_full());
+}
+
 #[test]
 fn set_len() {
   let mut v = staticvec![1, 2, 3];
@@ -807,6 +3224,74 @@ fn set_len() {
   assert_eq!(v.len(), 0);
 }
 
+#[cfg(feature = "std")]
+#[test]
+fn sort() {
+  let mut v = staticvec![-5, 4, 1, -3, 2];
+  v.sort();
+  assert_eq!(v, [-5, -3, 1, 2, 4]);
+}
+
+#[test]
+fn sort_and_dedup() {
+  let mut v = staticvec![4, 1, 4, 2, 1, 3, 2, 4];
+  v.sort_and_dedup();
+  assert_eq!(v, [1, 2, 3, 4]);
+  let mut v2 = staticvec![String::from("b"), String::from("a"), String::from("b")];
+  v2.sort_and_dedup();
+  assert_eq!(v2, ["a", "b"]);
+  let mut v3 = StaticVec::<i32, 4>::new();
+  v3.sort_and_dedup();
+  assert_eq!(v3, []);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sort_by() {
+  let mut v = staticvec![-5, 4, 1, -3, 2];
+  v.sort_by(|a, b| b.cmp(a));
+  assert_eq!(v, [4, 2, 1, -3, -5]);
+  // Stability: equal elements keep their original relative order.
+  let mut v2 = staticvec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+  v2.sort_by(|a, b| a.0.cmp(&b.0));
+  assert_eq!(v2, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sort_by_key() {
+  let mut v = staticvec![-5i32, 4, 1, -3, 2];
+  v.sort_by_key(|i| i.abs());
+  assert_eq!(v, [1, 2, -3, 4, -5]);
+  let mut v2 = staticvec!["bb", "a", "cc", "d", "e"];
+  v2.sort_by_key(|s| s.len());
+  assert_eq!(v2, ["a", "d", "e", "bb", "cc"]);
+}
+
+#[test]
+fn sort_unstable() {
+  let mut v = staticvec![-5, 4, 1, -3, 2];
+  v.sort_unstable();
+  assert_eq!(v, [-5, -3, 1, 2, 4]);
+}
+
+#[test]
+fn sort_unstable_by() {
+  let mut v = staticvec![-5, 4, 1, -3, 2];
+  v.sort_unstable_by(|a, b| b.cmp(a));
+  assert_eq!(v, [4, 2, 1, -3, -5]);
+}
+
+#[test]
+fn sort_unstable_by_key() {
+  let mut v = staticvec![-5i32, 4, 1, -3, 2];
+  v.sort_unstable_by_key(|i| i.abs());
+  assert_eq!(v, [1, 2, -3, 4, -5]);
+  let mut v2 = staticvec![String::from("ccc"), String::from("a"), String::from("bb")];
+  v2.sort_unstable_by_key(|s| s.len());
+  assert_eq!(v2, ["a", "bb", "ccc"]);
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn sorted() {
@@ -820,6 +3305,146 @@ fn sorted_unstable() {
   assert!(v == [-5, -3, 1, 2, 4]);
 }
 
+#[test]
+fn spare_capacity_mut() {
+  let mut v = StaticVec::<i32, 5>::from([1, 2]);
+  let spare = v.spare_capacity_mut();
+  assert_eq!(spare.len(), 3);
+  spare[0] = core::mem::MaybeUninit::new(3);
+  spare[1] = core::mem::MaybeUninit::new(4);
+  unsafe { v.set_len(4) };
+  assert_eq!(v, [1, 2, 3, 4]);
+  assert_eq!(v.spare_capacity_mut().len(), 1);
+  v.push(5);
+  assert!(v.spare_capacity_mut().is_empty());
+  assert_eq!(StaticVec::<i32, 0>::new().spare_capacity_mut().len(), 0);
+}
+
+#[test]
+fn splice() {
+  let mut v = staticvec![1, 2, 3, 4, 5];
+  let removed = v.splice(1..3, staticvec![7, 8]);
+  assert_eq!(removed, [2, 3]);
+  assert_eq!(v, [1, 7, 8, 4, 5]);
+  let mut v2 = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4]);
+  let removed2 = v2.splice(1..2, vec![10, 11, 12, 13]);
+  assert_eq!(removed2, [2]);
+  assert_eq!(v2, [1, 10, 11, 12, 13, 3, 4]);
+  let removed3 = v2.splice(..=3, Some(0));
+  assert_eq!(removed3, [1, 10, 11, 12]);
+  assert_eq!(v2, [0, 13, 3, 4]);
+  let removed4 = v2.splice(2.., None);
+  assert_eq!(removed4, [3, 4]);
+  assert_eq!(v2, [0, 13]);
+  let removed5 = v2.splice(2..2, 5..7);
+  assert_eq!(removed5, []);
+  assert_eq!(v2, [0, 13, 5, 6]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v = staticvec![1, 2, 3];
+      v.splice(1..2, vec![4, 5]);
+    });
+    assert_panics!({
+      let mut v = staticvec![1, 2, 3];
+      v.splice(2..4, None);
+    });
+  }
+}
+
+#[test]
+fn split_at() {
+  let v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  let (left, right) = v.split_at(2);
+  assert_eq!(left, [1, 2]);
+  assert_eq!(right, [3, 4, 5]);
+  assert_eq!(left.len() + right.len(), v.len());
+  let (left, right) = v.split_at(0);
+  assert!(left.is_empty());
+  assert_eq!(right, [1, 2, 3, 4, 5]);
+  let (left, right) = v.split_at(5);
+  assert_eq!(left, [1, 2, 3, 4, 5]);
+  assert!(right.is_empty());
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      v.split_at(6);
+    });
+  }
+}
+
+#[test]
+fn split_at_mut() {
+  let mut v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4, 5]);
+  {
+    let (left, right) = v.split_at_mut(3);
+    assert_eq!(left, [1, 2, 3]);
+    assert_eq!(right, [4, 5]);
+    left[0] = right[1];
+    right[0] += left[1];
+  }
+  assert_eq!(v, [5, 2, 3, 6, 5]);
+  let (left, right) = v.split_at_mut(0);
+  assert!(left.is_empty());
+  assert_eq!(right.len(), 5);
+  let (left, right) = v.split_at_mut(5);
+  assert_eq!(left.len(), 5);
+  assert!(right.is_empty());
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      staticvec![1, 2].split_at_mut(3);
+    });
+  }
+}
+
+#[test]
+fn split_first() {
+  let empty = StaticVec::<i32, 4>::new();
+  assert_eq!(empty.split_first(), None);
+  let single = staticvec![1];
+  assert_eq!(single.split_first(), Some((&1, &[][..])));
+  let multi = staticvec![1, 2, 3];
+  assert_eq!(multi.split_first(), Some((&1, &[2, 3][..])));
+}
+
+#[test]
+fn split_first_mut() {
+  let mut empty = StaticVec::<i32, 4>::new();
+  assert_eq!(empty.split_first_mut(), None);
+  let mut v = staticvec![1, 2, 3];
+  if let Some((head, tail)) = v.split_first_mut() {
+    *head += 10;
+    tail[1] = 30;
+  }
+  assert_eq!(v, [11, 2, 30]);
+}
+
+#[test]
+fn split_last() {
+  let empty = StaticVec::<i32, 4>::new();
+  assert_eq!(empty.split_last(), None);
+  let single = staticvec![1];
+  assert_eq!(single.split_last(), Some((&1, &[][..])));
+  let multi = staticvec![1, 2, 3];
+  assert_eq!(multi.split_last(), Some((&3, &[1, 2][..])));
+}
+
+#[test]
+fn split_last_mut() {
+  let mut empty = StaticVec::<i32, 4>::new();
+  assert_eq!(empty.split_last_mut(), None);
+  let mut v = staticvec![1, 2, 3];
+  if let Some((last, init)) = v.split_last_mut() {
+    *last += 10;
+    init[0] = 10;
+  }
+  assert_eq!(v, [10, 2, 13]);
+}
+
 #[test]
 fn split_off() {
   let mut vec = staticvec![1, 2, 3];
@@ -828,6 +3453,117 @@ fn split_off() {
   assert_eq!(vec2, [2, 3]);
 }
 
+#[test]
+fn split_off_front() {
+  let mut vec = staticvec![
+    String::from("a"),
+    String::from("b"),
+    String::from("c"),
+    String::from("d")
+  ];
+  let front = vec.split_off_front(3);
+  assert_eq!(front, ["a", "b", "c"]);
+  assert_eq!(vec, ["d"]);
+  assert_eq!(front.capacity(), 4);
+  let mut vec2 = staticvec![1, 2, 3];
+  assert_eq!(vec2.split_off_front(0), []);
+  assert_eq!(vec2, [1, 2, 3]);
+  assert_eq!(vec2.split_off_front(3), [1, 2, 3]);
+  assert_eq!(vec2, []);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v = staticvec![1, 2, 3];
+      v.split_off_front(4);
+    });
+  }
+}
+
+#[test]
+fn split_when() {
+  let line = StaticVec::<u8, 16>::new_from_slice(b"ab,cde,,f");
+  let fields: StaticVec<StaticVec<u8, 3>, 4> = line.split_when(|&b| b == b',');
+  assert_eq!(fields.len(), 4);
+  assert_eq!(fields[0], *b"ab");
+  assert_eq!(fields[1], *b"cde");
+  assert_eq!(fields[2], []);
+  assert_eq!(fields[3], *b"f");
+  let none: StaticVec<StaticVec<u8, 3>, 1> = StaticVec::<u8, 4>::new().split_when(|&b| b == 0);
+  assert_eq!(none.len(), 1);
+  assert!(none[0].is_empty());
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<StaticVec<u8, 3>, 3> = line.split_when(|&b| b == b',');
+    });
+    assert_panics!({
+      let _: StaticVec<StaticVec<u8, 2>, 4> = line.split_when(|&b| b == b',');
+    });
+  }
+}
+
+#[test]
+fn splitn() {
+  let v = staticvec![1, 0, 2, 3, 0, 4];
+  let parts: StaticVec<&[i32], 2> = v.splitn(2, |&x| x == 0).collect();
+  assert_eq!(parts, [&[1][..], &[2, 3, 0, 4][..]]);
+  assert_eq!(v.splitn(1, |&x| x == 0).next(), Some(v.as_slice()));
+}
+
+#[test]
+fn starts_with() {
+  let v = staticvec![1, 2, 3];
+  assert!(v.starts_with(&[]));
+  assert!(v.starts_with(&[1]));
+  assert!(v.starts_with(&[1, 2]));
+  assert!(v.starts_with(&[1, 2, 3]));
+  assert!(!v.starts_with(&[2]));
+  assert!(!v.starts_with(&[1, 2, 3, 4]));
+  assert!(StaticVec::<i32, 2>::new().starts_with(&[]));
+}
+
+#[test]
+fn sum() {
+  let v = staticvec![i32::max_value(), i32::max_value(), 2];
+  assert_eq!(
+    v.mapped(|&x| x as i64).sum::<i64>(),
+    2 * i32::max_value() as i64 + 2
+  );
+  let v2 = staticvec![1, -2, 3];
+  assert_eq!(v2.sum::<i32>(), 2);
+  assert_eq!(StaticVec::<i32, 4>::new().sum::<i32>(), 0);
+  struct Wide(i64);
+  impl<'a> core::iter::Sum<&'a i32> for Wide {
+    fn sum<I: Iterator<Item = &'a i32>>(iter: I) -> Self {
+      Wide(iter.map(|&x| x as i64).sum())
+    }
+  }
+  assert_eq!(v.sum::<Wide>().0, 2 * i32::max_value() as i64 + 2);
+}
+
+#[test]
+fn swap() {
+  let mut v = staticvec![1, 2, 3, 4];
+  v.swap(1, 1);
+  assert_eq!(v, [1, 2, 3, 4]);
+  v.swap(1, 2);
+  assert_eq!(v, [1, 3, 2, 4]);
+  v.swap(3, 0);
+  assert_eq!(v, [4, 3, 2, 1]);
+  let mut v2 = staticvec![String::from("a"), String::from("b")];
+  v2.swap(0, 1);
+  assert_eq!(v2, [String::from("b"), String::from("a")]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!(staticvec![1, 2, 3].swap(0, 3));
+    assert_panics!(staticvec![1, 2, 3].swap(3, 0));
+    assert_panics!(StaticVec::<i32, 4>::new().swap(0, 0));
+  }
+}
+
 #[test]
 fn swap_pop() {
   let mut v = staticvec!["foo", "bar", "baz", "qux"];
@@ -847,6 +3583,99 @@ fn swap_remove() {
   assert_eq!(v, ["baz", "qux"]);
 }
 
+#[test]
+fn swap_unchecked() {
+  let mut v = staticvec![1, 2, 3, 4];
+  unsafe {
+    v.swap_unchecked(2, 2);
+    assert_eq!(v, [1, 2, 3, 4]);
+    v.swap_unchecked(0, 1);
+    assert_eq!(v, [2, 1, 3, 4]);
+    v.swap_unchecked(3, 0);
+  }
+  assert_eq!(v, [4, 1, 3, 2]);
+}
+
+#[test]
+fn swap_with_slice() {
+  let mut v = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  let mut buffer = [String::from("x"), String::from("y"), String::from("z")];
+  v.swap_with_slice(&mut buffer);
+  assert_eq!(v, ["x", "y", "z"]);
+  assert_eq!(buffer, ["a", "b", "c"]);
+  v.swap_with_slice(&mut buffer);
+  assert_eq!(v, ["a", "b", "c"]);
+  assert_eq!(buffer, ["x", "y", "z"]);
+  let mut empty = StaticVec::<i32, 4>::new();
+  empty.swap_with_slice(&mut []);
+  assert!(empty.is_empty());
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v2 = staticvec![1, 2, 3];
+      v2.swap_with_slice(&mut [4, 5]);
+    });
+    assert_panics!({
+      let mut v2 = StaticVec::<i32, 4>::from([1]);
+      v2.swap_with_slice(&mut [4, 5]);
+    });
+  }
+}
+
+#[test]
+fn to_capacity() {
+  let v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3]);
+  let exact: StaticVec<i32, 3> = v.to_capacity();
+  assert_eq!(exact, [1, 2, 3]);
+  assert!(exact.is_full());
+  let larger: StaticVec<i32, 16> = v.to_capacity();
+  assert_eq!(larger, [1, 2, 3]);
+  assert_eq!(larger.capacity(), 16);
+  assert_eq!(v, [1, 2, 3]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<i32, 2> = v.to_capacity();
+    });
+  }
+}
+
+#[test]
+fn to_hex() {
+  let v = staticvec![0x0fu8, 0xa0];
+  let hex: StaticVec<u8, 4> = v.to_hex();
+  assert_eq!(hex, *b"0fa0");
+  let upper: StaticVec<u8, 8> = staticvec![0xdeu8, 0xad, 0xbe, 0xef].to_hex_upper();
+  assert_eq!(upper, *b"DEADBEEF");
+  let empty: StaticVec<u8, 0> = StaticVec::<u8, 4>::new().to_hex();
+  assert!(empty.is_empty());
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<u8, 3> = v.to_hex();
+    });
+  }
+}
+
+#[test]
+fn to_sorted_unique() {
+  let v = staticvec![4, 1, 4, 2, 1, 3, 2, 4];
+  let u: StaticVec<i32, 4> = v.to_sorted_unique();
+  assert_eq!(u, [1, 2, 3, 4]);
+  assert!(u.windows(2).all(|w| w[0] < w[1]));
+  assert_eq!(v, [4, 1, 4, 2, 1, 3, 2, 4]);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<i32, 3> = staticvec![4, 1, 4, 2, 1, 3].to_sorted_unique();
+    });
+  }
+}
+
 #[test]
 fn truncate() {
   let mut vec = staticvec![1, 2, 3, 4, 5];
@@ -863,6 +3692,36 @@ fn truncate() {
   assert_eq!(vec4.len(), 4);
 }
 
+#[test]
+fn try_append() {
+  let mut a = StaticVec::<String, 5>::new();
+  a.push(String::from("a"));
+  let mut b = staticvec![String::from("b"), String::from("c")];
+  assert!(a.try_append(&mut b).is_ok());
+  assert_eq!(a, ["a", "b", "c"]);
+  assert!(b.is_empty());
+  let mut c = staticvec![String::from("d"), String::from("e")];
+  assert!(a.try_append(&mut c).is_ok());
+  assert_eq!(a, ["a", "b", "c", "d", "e"]);
+  assert!(c.is_empty());
+  assert!(a.is_full());
+  let mut d = staticvec![String::from("f")];
+  assert_eq!(
+    a.try_append(&mut d),
+    Err("Insufficient remaining capacity!")
+  );
+  assert_eq!(a, ["a", "b", "c", "d", "e"]);
+  assert_eq!(d, ["f"]);
+  let mut e = StaticVec::<i32, 4>::from([1, 2]);
+  let mut f = staticvec![3, 4, 5];
+  assert!(e.try_append(&mut f).is_err());
+  assert_eq!(e, [1, 2]);
+  assert_eq!(f, [3, 4, 5]);
+  let mut g = StaticVec::<i32, 0>::new();
+  assert!(e.try_append(&mut g).is_ok());
+  assert_eq!(e, [1, 2]);
+}
+
 #[test]
 fn try_extend_from_slice() {
   let mut v = StaticVec::<i32, 3>::from([1, 2, 3]);
@@ -874,6 +3733,85 @@ fn try_extend_from_slice() {
   assert_eq!(w.try_extend_from_slice(&[2]), Ok(()));
 }
 
+#[test]
+fn try_filled_with() {
+  let mut i = 0;
+  let v = StaticVec::<i32, 4>::try_filled_with(|| -> Result<i32, ()> {
+    i += 1;
+    Ok(i)
+  });
+  assert_eq!(v, Ok(staticvec![1, 2, 3, 4]));
+  let lifespan_tracker = LifespanCounter::default();
+  let mut calls = 0;
+  let v2 = StaticVec::<LifespanCountingInstance, 5>::try_filled_with(|| {
+    calls += 1;
+    if calls == 3 {
+      Err("Failed on the third call!")
+    } else {
+      Ok(lifespan_tracker.instance())
+    }
+  });
+  assert_eq!(v2.unwrap_err(), "Failed on the third call!");
+  assert_eq!(calls, 3);
+  assert_eq!(lifespan_tracker.init_count(), 2);
+  assert_eq!(lifespan_tracker.drop_count(), 2);
+}
+
+#[test]
+fn try_from_bytes() {
+  let source = staticvec![7u32, 8, 9, 10];
+  let bytes = source.as_bytes();
+  assert_eq!(
+    StaticVec::<u32, 4>::try_from_bytes(bytes),
+    Ok(source.clone())
+  );
+  // The input doesn't need to be aligned.
+  let mut unaligned = StaticVec::<u8, 17>::new();
+  unaligned.push(0);
+  unaligned.extend_from_slice(bytes);
+  assert_eq!(
+    StaticVec::<u32, 4>::try_from_bytes(&unaligned[1..]),
+    Ok(source.clone())
+  );
+  assert_eq!(
+    StaticVec::<u32, 4>::try_from_bytes(&bytes[..6]),
+    Err("Length of `bytes` must be a multiple of the size of `T`!")
+  );
+  assert_eq!(
+    StaticVec::<u32, 3>::try_from_bytes(bytes),
+    Err("Insufficient remaining capacity!")
+  );
+  assert_eq!(
+    StaticVec::<u32, 3>::try_from_bytes(&[]),
+    Ok(StaticVec::new())
+  );
+  assert_eq!(
+    StaticVec::<[u8; 0], 3>::try_from_bytes(&[]),
+    Ok(StaticVec::new())
+  );
+  assert!(StaticVec::<[u8; 0], 3>::try_from_bytes(&[1]).is_err());
+}
+
+#[test]
+fn try_from_iter() {
+  let v = StaticVec::<i32, 4>::try_from_iter(0..4).unwrap();
+  assert_eq!(v, [0, 1, 2, 3]);
+  let v2 = StaticVec::<i32, 4>::try_from_iter(0..2).unwrap();
+  assert_eq!(v2, [0, 1]);
+  assert!(StaticVec::<i32, 4>::try_from_iter(None).unwrap().is_empty());
+  assert_eq!(
+    StaticVec::<i32, 4>::try_from_iter(0..5),
+    Err("Insufficient remaining capacity!")
+  );
+  assert!(StaticVec::<i32, 0>::try_from_iter(Some(1)).is_err());
+  let c = LifespanCounter::default();
+  let mut it = (0..10).map(|_| c.instance());
+  assert!(StaticVec::<_, 4>::try_from_iter(&mut it).is_err());
+  assert_eq!(c.init_count(), 5);
+  assert_eq!(c.drop_count(), 5);
+  assert_eq!(it.count(), 5);
+}
+
 #[allow(unused_must_use)]
 #[test]
 fn try_insert() {
@@ -887,6 +3825,19 @@ fn try_insert() {
   assert_eq!(vec2, [1, 2, 3, 3]);
 }
 
+#[test]
+fn try_insert_from_slice() {
+  let mut vec = StaticVec::<i32, 5>::new_from_slice(&[1, 2, 3]);
+  assert_eq!(
+    vec.try_insert_from_slice(1, &[4, 5, 6]),
+    Err("One of `self.length + other.len() <= N` or `index <= self.length` is false!")
+  );
+  assert!(vec.try_insert_from_slice(4, &[4]).is_err());
+  assert_eq!(vec, [1, 2, 3]);
+  assert!(vec.try_insert_from_slice(1, &[4, 5]).is_ok());
+  assert_eq!(vec, [1, 4, 5, 2, 3]);
+}
+
 #[test]
 fn try_push() {
   let mut vec = staticvec![1, 2, 3, 4, 5];
@@ -896,6 +3847,140 @@ fn try_push() {
   assert_eq!(vec2, [1, 2, 3, 3]);
 }
 
+#[test]
+fn try_push_front() {
+  let mut vec = StaticVec::<i32, 3>::new();
+  assert_eq!(vec.try_push_front(2), Ok(()));
+  assert_eq!(vec.try_push_front(1), Ok(()));
+  assert_eq!(vec.try_push(3), Ok(()));
+  assert_eq!(vec, [1, 2, 3]);
+  assert_eq!(
+    vec.try_push_front(0),
+    Err("Insufficient remaining capacity!")
+  );
+  assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+fn try_remove() {
+  let mut v = staticvec![String::from("a"), String::from("b"), String::from("c")];
+  assert_eq!(v.try_remove(1), Some(String::from("b")));
+  assert_eq!(v, ["a", "c"]);
+  assert_eq!(v.try_remove(2), None);
+  assert_eq!(v, ["a", "c"]);
+  assert_eq!(v.try_remove(1), Some(String::from("c")));
+  assert_eq!(v.try_remove(0), Some(String::from("a")));
+  assert_eq!(v.try_remove(0), None);
+  assert!(v.is_empty());
+}
+
+#[test]
+fn try_resize() {
+  let mut vec = StaticVec::<i32, 4>::new_from_slice(&[1, 2]);
+  assert_eq!(
+    vec.try_resize(5, 0),
+    Err("Insufficient remaining capacity!")
+  );
+  assert_eq!(vec, [1, 2]);
+  assert_eq!(vec.try_resize(4, 7), Ok(()));
+  assert_eq!(vec, [1, 2, 7, 7]);
+  assert_eq!(vec.try_resize(1, 7), Ok(()));
+  assert_eq!(vec, [1]);
+}
+
+#[test]
+fn try_swap_remove() {
+  let mut v = staticvec![1, 2, 3, 4];
+  assert_eq!(v.try_swap_remove(0), Some(1));
+  assert_eq!(v, [4, 2, 3]);
+  assert_eq!(v.try_swap_remove(3), None);
+  assert_eq!(v, [4, 2, 3]);
+  assert_eq!(v.try_swap_remove(2), Some(3));
+  assert_eq!(v, [4, 2]);
+  let mut empty = StaticVec::<i32, 4>::new();
+  assert_eq!(empty.try_swap_remove(0), None);
+  assert!(empty.is_empty());
+}
+
+#[test]
+fn union() {
+  let a = staticvec![1, 3, 5, 7, 9];
+  let overlapping: StaticVec<i32, 10> = a.union(&staticvec![3, 4, 5, 10]);
+  assert_eq!(overlapping, [1, 3, 4, 5, 7, 9, 10]);
+  let disjoint: StaticVec<i32, 7> = a.union(&staticvec![2, 4]);
+  assert_eq!(disjoint, [1, 2, 3, 4, 5, 7, 9]);
+  let subset: StaticVec<i32, 5> = a.union(&staticvec![1, 5, 9]);
+  assert_eq!(subset, a);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let _: StaticVec<i32, 3> = staticvec![1, 2].union(&staticvec![3, 4]);
+    });
+  }
+}
+
+#[test]
+fn windows() {
+  let v = StaticVec::<i32, 8>::new_from_slice(&[1, 2, 3, 4]);
+  let sums: StaticVec<i32, 8> = v.windows(3).map(|w| w.iter().sum::<i32>()).collect();
+  assert_eq!(sums, [6, 9]);
+  assert_eq!(v.windows(4).count(), 1);
+  assert_eq!(v.windows(5).count(), 0);
+}
+
+#[test]
+fn windows_mut() {
+  let mut v = staticvec![1, 0, 0, 0, 0];
+  let mut windows = v.windows_mut(2);
+  assert_eq!(windows.len(), 4);
+  while let Some(window) = windows.next() {
+    window[1] = window[0] * 2;
+  }
+  assert!(windows.is_empty());
+  assert_eq!(windows.next(), None);
+  assert_eq!(v, [1, 2, 4, 8, 16]);
+  let mut windows2 = v.windows_mut(5);
+  assert_eq!(windows2.next(), Some(&mut [1, 2, 4, 8, 16][..]));
+  assert_eq!(windows2.next(), None);
+  let mut windows3 = v.windows_mut(6);
+  assert_eq!(windows3.len(), 0);
+  assert_eq!(windows3.next(), None);
+  let mut empty = StaticVec::<i32, 4>::new();
+  assert_eq!(empty.windows_mut(1).next(), None);
+  #[cfg(not(miri))]
+  #[cfg(feature = "std")]
+  {
+    assert_panics!({
+      let mut v = staticvec![1, 2];
+      v.windows_mut(0);
+    });
+  }
+}
+
+#[test]
+fn with_len() {
+  let v = unsafe {
+    let mut v = StaticVec::<u32, 8>::with_len(5);
+    assert_eq!(v.len(), 5);
+    let p = v.as_mut_ptr();
+    for i in 0..5 {
+      p.add(i).write(i as u32 * 3);
+    }
+    v
+  };
+  assert_eq!(v, [0, 3, 6, 9, 12]);
+  assert_eq!(v.capacity(), 8);
+  let strings = unsafe {
+    let mut strings = StaticVec::<String, 2>::with_len(2);
+    strings.as_mut_ptr().write(String::from("a"));
+    strings.as_mut_ptr().add(1).write(String::from("b"));
+    strings
+  };
+  assert_eq!(strings, ["a", "b"]);
+  assert!(unsafe { StaticVec::<i32, 4>::with_len(0) }.is_empty());
+}
+
 #[cfg(feature = "std")]
 #[test]
 fn write() {
@@ -906,15 +3991,34 @@ fn write() {
   let r = v.write(&[9; 16]).unwrap();
   assert_eq!(r, 5);
   assert_eq!(&v[..], &[1, 2, 3, 9, 9, 9, 9, 9]);
+  assert_eq!(v.write(&[10]).unwrap(), 0);
+  assert_eq!(v.write(&[]).unwrap(), 0);
+  assert!(v.flush().is_ok());
+  let mut v2 = StaticVec::<u8, 5>::new();
+  assert_eq!(v2.write(&[1, 2]).unwrap(), 2);
+  assert_eq!(v2.write(&[3, 4]).unwrap(), 2);
+  assert_eq!(v2.write(&[5, 6]).unwrap(), 1);
+  assert_eq!(v2, [1, 2, 3, 4, 5]);
+  let mut v3 = StaticVec::<u8, 4>::new();
+  // `write!` goes through `write_all`, which never writes partially.
+  assert!(write!(&mut v3, "{}", 123456).is_err());
+  assert!(v3.is_empty());
+  assert!(write!(&mut v3, "{}{}", 12, 345).is_err());
+  assert_eq!(v3, *b"12");
 }
 
 #[cfg(feature = "std")]
 #[test]
 fn write_all() {
   let mut v = StaticVec::<u8, 6>::new();
-  assert!(v.write_all(&[1, 2, 3, 4, 5, 6, 7, 8]).is_err());
-  v.clear();
+  let err = v.write_all(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap_err();
+  assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+  assert!(v.is_empty());
   assert!(v.write_all(&[1, 2, 3, 4, 5, 6]).is_ok());
+  assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+  assert!(v.write_all(&[]).is_ok());
+  assert!(v.write_all(&[7]).is_err());
+  assert_eq!(v, [1, 2, 3, 4, 5, 6]);
 }
 
 #[cfg(feature = "std")]
@@ -928,3 +4032,13 @@ fn write_vectored() {
   );
   assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 8]);
 }
+
+#[test]
+fn zeroize() {
+  let mut v = StaticVec::<u8, 8>::new_from_slice(&[0xAB; 8]);
+  v.truncate(5);
+  v.zeroize();
+  assert!(v.is_empty());
+  let bytes = unsafe { core::slice::from_raw_parts(v.as_ptr(), v.capacity()) };
+  assert_eq!(bytes, [0; 8]);
+}